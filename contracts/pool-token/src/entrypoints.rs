@@ -2,20 +2,20 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use cw20::{
-    AllAccountsResponse, BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, DownloadLogoResponse,
-    MarketingInfoResponse, MinterResponse, TokenInfoResponse,
+    AllAccountsResponse, BalanceResponse, Cw20ExecuteMsg, MinterResponse, TokenInfoResponse,
 };
 use cw20_base::allowances::{
     execute_decrease_allowance, execute_increase_allowance, query_allowance,
 };
+use cw20_base::contract::{query_download_logo, query_marketing_info};
 use cw20_base::enumerable::query_all_allowances;
-use cw20_base::state::{TokenInfo, TOKEN_INFO};
+use cw20_base::state::{TokenInfo, MARKETING_INFO, TOKEN_INFO};
 use cw20_base::ContractError;
-use pylon_gateway::pool_token_msg::{InstantiateMsg, MigrateMsg};
+use pylon_gateway::pool_token_msg::{ConfigResponse, InstantiateMsg, MigrateMsg, QueryMsg};
 
 use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION};
 use crate::executions;
@@ -40,19 +40,37 @@ pub fn instantiate(
     let reward_token_info =
         querier.load_token_info(&deps.api.addr_validate(pool_config.reward_token.as_str())?)?;
 
-    let distribution_start = pool_config.reward_distribution_time.start;
-    let distribution_finish = pool_config.reward_distribution_time.finish;
-    let months = (distribution_finish - distribution_start) / (30 * 86400);
+    let share_token_info =
+        querier.load_token_info(&deps.api.addr_validate(pool_config.share_token.as_str())?)?;
+
+    // labels the distribution window in months only when it divides evenly
+    let days = (pool_config.reward_distribution_time.finish
+        - pool_config.reward_distribution_time.start)
+        / 86400;
+    let period = if days != 0 && days % 30 == 0 {
+        format!("{}m", days / 30)
+    } else {
+        format!("{}d", days)
+    };
+
+    let decimals = msg.decimals.unwrap_or(share_token_info.decimals);
+    if decimals > 18 {
+        return Err(StdError::generic_err("Decimals must not exceed 18").into());
+    }
 
     TOKEN_INFO.save(
         deps.storage,
         &TokenInfo {
-            name: format!(
-                "Pylon bDP Token for Gateway {} {}m Pool",
-                reward_token_info.symbol, months
-            ),
-            symbol: format!("b{}DP-{}m", reward_token_info.symbol, months),
-            decimals: 6,
+            name: msg.name.unwrap_or_else(|| {
+                format!(
+                    "Pylon bDP Token for Gateway {} {} Pool",
+                    reward_token_info.symbol, period
+                )
+            }),
+            symbol: msg
+                .symbol
+                .unwrap_or_else(|| format!("b{}DP-{}", reward_token_info.symbol, period)),
+            decimals,
             total_supply: Uint128::zero(),
             mint: None,
         },
@@ -60,6 +78,8 @@ pub fn instantiate(
 
     Config::save(deps.storage, &Config { pool: pool_addr })?;
 
+    executions::init_marketing(deps, &pool_config)?;
+
     Ok(Response::default())
 }
 
@@ -101,22 +121,39 @@ pub fn execute(
             amount,
             expires,
         } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        Cw20ExecuteMsg::Burn { amount } => executions::execute_burn(deps, env, info, amount),
+        Cw20ExecuteMsg::BurnFrom { owner, amount } => {
+            executions::execute_burn_from(deps, env, info, owner, amount)
+        }
+        Cw20ExecuteMsg::UpdateMarketing {
+            project,
+            description,
+            marketing,
+        } => {
+            // marketing address always follows the pool owner
+            if marketing.is_some() {
+                return Err(StdError::generic_err(
+                    "marketing address follows the pool owner and can not be set",
+                )
+                .into());
+            }
+            executions::execute_update_pool_marketing(deps, env, info, project, description)
+        }
+        Cw20ExecuteMsg::UploadLogo(logo) => {
+            executions::execute_upload_pool_logo(deps, env, info, logo)
+        }
         // not used
         Cw20ExecuteMsg::Mint { .. } => Err(ContractError::Unauthorized {}),
-        Cw20ExecuteMsg::Burn { .. } => Err(ContractError::Unauthorized {}),
-        Cw20ExecuteMsg::BurnFrom { .. } => Err(ContractError::Unauthorized {}),
-        Cw20ExecuteMsg::UpdateMarketing { .. } => Err(ContractError::Unauthorized {}),
-        Cw20ExecuteMsg::UploadLogo(_) => Err(ContractError::Unauthorized {}),
     }
 }
 
 #[allow(dead_code)]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let querier = Querier::new(&deps.querier);
 
     match msg {
-        Cw20QueryMsg::Balance { address } => {
+        QueryMsg::Balance { address } => {
             let config = Config::load(deps.storage)?;
             let staker = querier
                 .load_pool_staker(&config.pool, &deps.api.addr_validate(address.as_str())?)?;
@@ -125,7 +162,7 @@ pub fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
                 balance: staker.staked,
             })
         }
-        Cw20QueryMsg::TokenInfo {} => {
+        QueryMsg::TokenInfo {} => {
             let config = Config::load(deps.storage)?;
             let pool_reward = querier.load_pool_reward(&config.pool)?;
 
@@ -139,10 +176,10 @@ pub fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
                 total_supply: token_info.total_supply,
             })
         }
-        Cw20QueryMsg::Allowance { owner, spender } => {
+        QueryMsg::Allowance { owner, spender } => {
             Ok(to_binary(&query_allowance(deps, owner, spender)?)?)
         }
-        Cw20QueryMsg::AllAllowances {
+        QueryMsg::AllAllowances {
             owner,
             start_after,
             limit,
@@ -152,27 +189,61 @@ pub fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         )?)?),
-        Cw20QueryMsg::AllAccounts { start_after, limit } => {
+        QueryMsg::AllAccounts { start_after, limit } => {
             let config = Config::load(deps.storage)?;
-            let pool_stakers = querier.load_pool_stakers(&config.pool, start_after, limit, None)?;
+            let pool_accounts = querier.load_pool_accounts(&config.pool, start_after, limit)?;
 
             to_binary(&AllAccountsResponse {
-                accounts: pool_stakers
-                    .stakers
-                    .iter()
-                    .map(|staker| staker.address.to_string())
-                    .collect(),
+                accounts: pool_accounts.accounts,
             })
         }
+        QueryMsg::MarketingInfo {} => to_binary(&query_marketing_info(deps)?),
+        QueryMsg::DownloadLogo {} => to_binary(&query_download_logo(deps)?),
         // not used
-        Cw20QueryMsg::Minter {} => to_binary(&Option::<MinterResponse>::None),
-        Cw20QueryMsg::MarketingInfo { .. } => to_binary(&Option::<MarketingInfoResponse>::None),
-        Cw20QueryMsg::DownloadLogo { .. } => to_binary(&Option::<DownloadLogoResponse>::None),
+        QueryMsg::Minter {} => to_binary(&Option::<MinterResponse>::None),
+        QueryMsg::BalanceAt { address, height } => {
+            let config = Config::load(deps.storage)?;
+            let staker = querier.load_pool_staker_at(
+                &config.pool,
+                &deps.api.addr_validate(address.as_str())?,
+                height,
+            )?;
+
+            to_binary(&BalanceResponse {
+                balance: staker.staked,
+            })
+        }
+        QueryMsg::TokenInfoAt { height } => {
+            let config = Config::load(deps.storage)?;
+            let pool_total_deposit = querier.load_pool_total_deposit_at(&config.pool, height)?;
+            let token_info = TOKEN_INFO.load(deps.storage)?;
+
+            to_binary(&TokenInfoResponse {
+                name: token_info.name,
+                symbol: token_info.symbol,
+                decimals: token_info.decimals,
+                total_supply: pool_total_deposit.total_deposit,
+            })
+        }
+        QueryMsg::Config {} => {
+            let config = Config::load(deps.storage)?;
+
+            to_binary(&ConfigResponse {
+                pool: config.pool.to_string(),
+            })
+        }
     }
 }
 
 #[allow(dead_code)]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // tokens instantiated without marketing info
+    if MARKETING_INFO.may_load(deps.storage)?.is_none() {
+        let config = Config::load(deps.storage)?;
+        let pool_config = Querier::new(&deps.querier).load_pool_config(&config.pool)?;
+        executions::init_marketing(deps, &pool_config)?;
+    }
+
     Ok(Response::default())
 }
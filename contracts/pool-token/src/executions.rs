@@ -2,11 +2,15 @@ use cosmwasm_std::{
     attr, to_binary, Addr, Binary, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult,
     Uint128, WasmMsg,
 };
-use cw20::Cw20ReceiveMsg;
+use cw20::{Cw20ReceiveMsg, Logo, LogoInfo, MarketingInfoResponse};
 use cw20_base::allowances::deduct_allowance;
+use cw20_base::contract::{execute_update_marketing, execute_upload_logo};
+use cw20_base::state::{LOGO, MARKETING_INFO};
 use cw20_base::ContractError;
 use pylon_gateway::pool_msg::ExecuteMsg as PoolExecuteMsg;
+use pylon_gateway::pool_resp_v2;
 
+use crate::querier::Querier;
 use crate::states::Config;
 
 pub type ExecuteResult = Result<Response, ContractError>;
@@ -16,7 +20,11 @@ fn to_transfer_messages(
     owner: &Addr,
     recipient: &Addr,
     amount: Uint128,
-) -> StdResult<Vec<CosmosMsg>> {
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    if owner == recipient {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
     Ok(vec![
         CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: pool.to_string(),
@@ -193,3 +201,126 @@ pub fn execute_send_from(
 
     Ok(res)
 }
+
+fn to_burn_messages(pool: &Addr, owner: &Addr, amount: Uint128) -> StdResult<Vec<CosmosMsg>> {
+    Ok(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: pool.to_string(),
+        msg: to_binary(&PoolExecuteMsg::BurnInternal {
+            owner: owner.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    })])
+}
+
+pub fn execute_burn(deps: DepsMut, _env: Env, info: MessageInfo, amount: Uint128) -> ExecuteResult {
+    if amount == Uint128::zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let pool_addr = Config::load(deps.storage).unwrap().pool;
+
+    let res = Response::new()
+        .add_attribute("action", "burn")
+        .add_attribute("from", info.sender.to_string())
+        .add_attribute("amount", amount)
+        .add_messages(to_burn_messages(&pool_addr, &info.sender, amount)?);
+
+    Ok(res)
+}
+
+pub fn execute_burn_from(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    amount: Uint128,
+) -> ExecuteResult {
+    if amount == Uint128::zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let pool_addr = Config::load(deps.storage).unwrap().pool;
+    let owner_addr = deps.api.addr_validate(&owner)?;
+
+    // deduct allowance before doing anything else have enough allowance
+    deduct_allowance(deps.storage, &owner_addr, &info.sender, &env.block, amount)?;
+
+    let res = Response::new()
+        .add_attributes(vec![
+            attr("action", "burn_from"),
+            attr("from", owner),
+            attr("by", info.sender),
+            attr("amount", amount),
+        ])
+        .add_messages(to_burn_messages(&pool_addr, &owner_addr, amount)?);
+
+    Ok(res)
+}
+
+// defaults follow the reward token. the marketing address is the pool owner
+pub fn init_marketing(
+    deps: DepsMut,
+    pool_config: &pool_resp_v2::ConfigResponse,
+) -> Result<(), ContractError> {
+    // reward tokens without marketing info leave the defaults empty
+    let reward_marketing_info = Querier::new(&deps.querier)
+        .load_token_marketing_info(&deps.api.addr_validate(pool_config.reward_token.as_str())?)?
+        .unwrap_or_default();
+    let logo = match reward_marketing_info.logo {
+        Some(LogoInfo::Url(url)) => {
+            LOGO.save(deps.storage, &Logo::Url(url.clone()))?;
+            Some(LogoInfo::Url(url))
+        }
+        _ => None,
+    };
+    MARKETING_INFO.save(
+        deps.storage,
+        &MarketingInfoResponse {
+            project: reward_marketing_info.project,
+            description: reward_marketing_info.description,
+            marketing: deps.api.addr_validate(pool_config.owner.as_str()).ok(),
+            logo,
+        },
+    )?;
+
+    Ok(())
+}
+
+// marketing address always follows the pool owner
+fn assign_marketing_to_pool_owner(deps: DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+    let config = Config::load(deps.storage)?;
+    let pool_config = Querier::new(&deps.querier).load_pool_config(&config.pool)?;
+    if pool_config.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut marketing_info = MARKETING_INFO.may_load(deps.storage)?.unwrap_or_default();
+    marketing_info.marketing = Some(info.sender.clone());
+    MARKETING_INFO.save(deps.storage, &marketing_info)?;
+
+    Ok(())
+}
+
+pub fn execute_update_pool_marketing(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    project: Option<String>,
+    description: Option<String>,
+) -> ExecuteResult {
+    assign_marketing_to_pool_owner(deps.branch(), &info)?;
+
+    execute_update_marketing(deps, env, info, project, description, None)
+}
+
+pub fn execute_upload_pool_logo(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    logo: Logo,
+) -> ExecuteResult {
+    assign_marketing_to_pool_owner(deps.branch(), &info)?;
+
+    execute_upload_logo(deps, env, info, logo)
+}
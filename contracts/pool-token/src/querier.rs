@@ -1,9 +1,11 @@
-use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
-use cw20::{Cw20QueryMsg, TokenInfoResponse};
+use cosmwasm_std::{
+    from_binary, to_binary, to_vec, Addr, ContractResult, Empty, QuerierWrapper, QueryRequest,
+    StdError, StdResult, SystemResult, WasmQuery,
+};
+use cw20::{Cw20QueryMsg, MarketingInfoResponse, TokenInfoResponse};
 use pylon_gateway::pool_msg;
 use pylon_gateway::pool_resp;
 use pylon_gateway::pool_resp_v2;
-use pylon_utils::common::OrderBy;
 
 pub struct Querier<'a> {
     querier: &'a QuerierWrapper<'a>,
@@ -45,23 +47,46 @@ impl Querier<'_> {
         Ok(pool_staker)
     }
 
-    pub fn load_pool_stakers(
+    pub fn load_pool_accounts(
         &self,
         pool: &Addr,
         start_after: Option<String>,
         limit: Option<u32>,
-        order: Option<OrderBy>,
-    ) -> StdResult<pool_resp::StakersResponse> {
-        let pool_stakers: pool_resp::StakersResponse = self.querier.query_wasm_smart(
+    ) -> StdResult<pool_resp_v2::AccountsResponse> {
+        let pool_accounts: pool_resp_v2::AccountsResponse = self
+            .querier
+            .query_wasm_smart(pool, &pool_msg::QueryMsg::Accounts { start_after, limit })?;
+
+        Ok(pool_accounts)
+    }
+
+    pub fn load_pool_staker_at(
+        &self,
+        pool: &Addr,
+        owner: &Addr,
+        height: u64,
+    ) -> StdResult<pool_resp_v2::StakerAtResponse> {
+        let pool_staker: pool_resp_v2::StakerAtResponse = self.querier.query_wasm_smart(
             pool,
-            &pool_msg::QueryMsg::Stakers {
-                start_after,
-                limit,
-                order,
+            &pool_msg::QueryMsg::StakerAt {
+                address: owner.to_string(),
+                height,
             },
         )?;
 
-        Ok(pool_stakers)
+        Ok(pool_staker)
+    }
+
+    pub fn load_pool_total_deposit_at(
+        &self,
+        pool: &Addr,
+        height: u64,
+    ) -> StdResult<pool_resp_v2::TotalDepositAtResponse> {
+        let pool_total_deposit: pool_resp_v2::TotalDepositAtResponse = self
+            .querier
+            .query_wasm_smart(pool, &pool_msg::QueryMsg::TotalDepositAt { height })?;
+
+        Ok(pool_total_deposit)
     }
 
     pub fn load_token_info(&self, token: &Addr) -> StdResult<TokenInfoResponse> {
@@ -71,4 +96,25 @@ impl Querier<'_> {
 
         Ok(dp_token_info)
     }
+
+    // None if the token rejects the query. e.g. cw20 without marketing extension
+    pub fn load_token_marketing_info(
+        &self,
+        token: &Addr,
+    ) -> StdResult<Option<MarketingInfoResponse>> {
+        let request: QueryRequest<Empty> = WasmQuery::Smart {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20QueryMsg::MarketingInfo {})?,
+        }
+        .into();
+
+        match self.querier.raw_query(&to_vec(&request)?) {
+            SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+                "Querier system error: {}",
+                system_err
+            ))),
+            SystemResult::Ok(ContractResult::Err(_)) => Ok(None),
+            SystemResult::Ok(ContractResult::Ok(value)) => Ok(Some(from_binary(&value)?)),
+        }
+    }
 }
@@ -0,0 +1,45 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, to_binary, CosmosMsg, Env, SubMsg, Uint128, WasmMsg};
+use pylon_gateway::pool_msg::ExecuteMsg as PoolExecuteMsg;
+
+use crate::executions::{execute_burn, ExecuteResult};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_POOL, TEST_SENDER};
+
+pub fn exec(deps: &mut MockDeps, env: Env, owner: &str, amount: u128) -> ExecuteResult {
+    execute_burn(
+        deps.as_mut(),
+        env,
+        mock_info(owner, &[]),
+        Uint128::from(amount),
+    )
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const BURN_AMOUNT: u128 = 1000;
+
+    let res = exec(&mut deps, mock_env(), TEST_SENDER, BURN_AMOUNT).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_POOL.to_string(),
+            msg: to_binary(&PoolExecuteMsg::BurnInternal {
+                owner: TEST_SENDER.to_string(),
+                amount: Uint128::from(BURN_AMOUNT)
+            })
+            .unwrap(),
+            funds: vec![]
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "burn"),
+            attr("from", TEST_SENDER.to_string()),
+            attr("amount", BURN_AMOUNT.to_string()),
+        ]
+    );
+}
@@ -0,0 +1,108 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, to_binary, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg};
+use cw20_base::allowances::execute_increase_allowance;
+use cw20_base::ContractError;
+use pylon_gateway::pool_msg::ExecuteMsg as PoolExecuteMsg;
+
+use crate::executions::{execute_burn_from, ExecuteResult};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_POOL, TEST_SENDER};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    owner: &str,
+    amount: u128,
+) -> ExecuteResult {
+    execute_burn_from(
+        deps.as_mut(),
+        env,
+        info,
+        owner.to_string(),
+        Uint128::from(amount),
+    )
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const BURN_AMOUNT: u128 = 1000;
+
+    execute_increase_allowance(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_SENDER, &[]),
+        TEST_OWNER.to_string(),
+        Uint128::from(BURN_AMOUNT),
+        None,
+    )
+    .unwrap();
+
+    let res = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        TEST_SENDER,
+        BURN_AMOUNT,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_POOL.to_string(),
+            msg: to_binary(&PoolExecuteMsg::BurnInternal {
+                owner: TEST_SENDER.to_string(),
+                amount: Uint128::from(BURN_AMOUNT)
+            })
+            .unwrap(),
+            funds: vec![]
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "burn_from"),
+            attr("from", TEST_SENDER.to_string()),
+            attr("by", TEST_OWNER.to_string()),
+            attr("amount", BURN_AMOUNT.to_string()),
+        ]
+    );
+}
+
+#[test]
+fn fail_no_allowance() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        TEST_SENDER,
+        1000,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NoAllowance {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_zero_amount() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        TEST_SENDER,
+        0,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidZeroAmount {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,132 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Api, Binary};
+use cw20::{
+    Cw20ExecuteMsg, Cw20QueryMsg, DownloadLogoResponse, EmbeddedLogo, Logo, LogoInfo,
+    MarketingInfoResponse,
+};
+use cw20_base::ContractError;
+
+use crate::entrypoints::{execute, query};
+use crate::testing::instantiate::TEST_PROJECT;
+use crate::testing::{instantiate, mock_deps, TEST_OWNER, TEST_SENDER};
+
+// minimal svg with an xml preamble
+const TEST_SVG: &[u8] = b"<?xml version=\"1.0\"?><svg></svg>";
+
+#[test]
+fn success_update_marketing() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Cw20ExecuteMsg::UpdateMarketing {
+            project: None,
+            description: Some("bDP token of the pool".to_string()),
+            marketing: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(res.attributes, vec![attr("action", "update_marketing")]);
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        Cw20QueryMsg::MarketingInfo {}.into(),
+    )
+    .unwrap();
+    let api = deps.api;
+    assert_eq!(
+        from_binary::<MarketingInfoResponse>(&response).unwrap(),
+        MarketingInfoResponse {
+            project: Some(TEST_PROJECT.to_string()),
+            description: Some("bDP token of the pool".to_string()),
+            marketing: Some(api.addr_validate(TEST_OWNER).unwrap()),
+            logo: Some(LogoInfo::Url(instantiate::TEST_LOGO_URL.to_string())),
+        }
+    );
+}
+
+#[test]
+fn success_upload_logo() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Cw20ExecuteMsg::UploadLogo(Logo::Embedded(EmbeddedLogo::Svg(Binary::from(TEST_SVG)))),
+    )
+    .unwrap();
+    assert_eq!(res.attributes, vec![attr("action", "upload_logo")]);
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        Cw20QueryMsg::DownloadLogo {}.into(),
+    )
+    .unwrap();
+    assert_eq!(
+        from_binary::<DownloadLogoResponse>(&response).unwrap(),
+        DownloadLogoResponse {
+            mime_type: "image/svg+xml".to_string(),
+            data: Binary::from(TEST_SVG),
+        }
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_SENDER, &[]),
+        Cw20ExecuteMsg::UpdateMarketing {
+            project: Some("".to_string()),
+            description: None,
+            marketing: None,
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_SENDER, &[]),
+        Cw20ExecuteMsg::UploadLogo(Logo::Url("https://example.com".to_string())),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_set_marketing_address() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Cw20ExecuteMsg::UpdateMarketing {
+            project: None,
+            description: None,
+            marketing: Some(TEST_SENDER.to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Std(_)) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -1,3 +1,6 @@
+pub mod burn;
+pub mod burn_from;
+pub mod marketing;
 pub mod send;
 pub mod send_from;
 pub mod transfer;
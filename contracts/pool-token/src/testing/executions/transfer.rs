@@ -1,5 +1,6 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
 use cosmwasm_std::{attr, to_binary, CosmosMsg, Env, SubMsg, Uint128, WasmMsg};
+use cw20_base::ContractError;
 use pylon_gateway::pool_msg::ExecuteMsg as PoolExecuteMsg;
 
 use crate::executions::{execute_transfer, ExecuteResult};
@@ -77,3 +78,15 @@ fn success() {
         ]
     );
 }
+
+#[test]
+fn fail_self_transfer() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(&mut deps, mock_env(), TEST_SENDER, TEST_SENDER, 1000) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::CannotSetOwnAccount {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
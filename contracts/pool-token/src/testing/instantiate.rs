@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{from_binary, to_binary, Api, Env, MessageInfo, Response, Uint128};
-use cw20::{Cw20QueryMsg, TokenInfoResponse};
-use cw20_base::state::{TokenInfo, TOKEN_INFO};
+use cosmwasm_std::{from_binary, to_binary, Api, Env, MessageInfo, Response, StdError, Uint128};
+use cw20::{Cw20QueryMsg, Logo, LogoInfo, MarketingInfoResponse, TokenInfoResponse};
+use cw20_base::state::{TokenInfo, LOGO, MARKETING_INFO, TOKEN_INFO};
 use pylon_gateway::pool_msg::{QueryMsg as PoolQueryMsg, QueryMsg};
 use pylon_gateway::pool_resp_v2::ConfigResponse;
 use pylon_gateway::pool_token_msg::InstantiateMsg;
@@ -10,7 +10,12 @@ use pylon_gateway::time_range::TimeRange;
 use crate::entrypoints::instantiate;
 use crate::executions::ExecuteResult;
 use crate::states::Config;
-use crate::testing::{mock_deps, MockDeps, TEST_OWNER, TEST_POOL, TEST_REWARD_TOKEN};
+use crate::testing::{
+    mock_deps, MockDeps, TEST_OWNER, TEST_POOL, TEST_REWARD_TOKEN, TEST_SHARE_TOKEN,
+};
+
+pub const TEST_PROJECT: &str = "https://pylon.money";
+pub const TEST_LOGO_URL: &str = "https://pylon.money/logo.png";
 
 pub fn exec(
     deps: &mut MockDeps,
@@ -22,20 +27,34 @@ pub fn exec(
 }
 
 pub fn default(deps: &mut MockDeps) -> (Env, MessageInfo, Response) {
+    register_query_handlers(deps, 150 * 86400);
+
+    let env = mock_env();
+    let info = mock_info(TEST_OWNER, &[]);
+    let res = exec(deps, env.clone(), info.clone(), default_msg()).unwrap();
+
+    (env, info, res)
+}
+
+pub fn register_query_handlers(deps: &mut MockDeps, distribution_period: u64) {
     deps.querier.register_wasm_smart_query_handler(
         TEST_POOL.to_string(),
-        Box::new(|x| match from_binary::<PoolQueryMsg>(x).unwrap() {
+        Box::new(move |x| match from_binary::<PoolQueryMsg>(x).unwrap() {
             QueryMsg::ConfigV2 {} => to_binary(&ConfigResponse {
-                owner: "".to_string(),
+                owner: TEST_OWNER.to_string(),
                 token: "".to_string(),
-                share_token: "".to_string(),
+                share_token: TEST_SHARE_TOKEN.to_string(),
                 deposit_time: vec![],
                 withdraw_time: vec![],
                 deposit_cap_strategy: None,
                 reward_token: TEST_REWARD_TOKEN.to_string(),
                 reward_rate: Default::default(),
                 reward_claim_time: vec![],
-                reward_distribution_time: TimeRange::from((30 * 86400, 180 * 86400, false)),
+                reward_distribution_time: TimeRange::from((
+                    30 * 86400,
+                    30 * 86400 + distribution_period,
+                    false,
+                )),
             }),
             _ => panic!("Unsupported query"),
         }),
@@ -50,20 +69,36 @@ pub fn default(deps: &mut MockDeps) -> (Env, MessageInfo, Response) {
                 decimals: 0,
                 total_supply: Default::default(),
             }),
+            Cw20QueryMsg::MarketingInfo {} => to_binary(&MarketingInfoResponse {
+                project: Some(TEST_PROJECT.to_string()),
+                description: None,
+                marketing: None,
+                logo: Some(LogoInfo::Url(TEST_LOGO_URL.to_string())),
+            }),
             _ => panic!("Unsupported query"),
         }),
     );
 
-    let env = mock_env();
-    let info = mock_info(TEST_OWNER, &[]);
-    let res = exec(deps, env.clone(), info.clone(), default_msg()).unwrap();
-
-    (env, info, res)
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_SHARE_TOKEN.to_string(),
+        Box::new(|x| match from_binary::<Cw20QueryMsg>(x).unwrap() {
+            Cw20QueryMsg::TokenInfo {} => to_binary(&TokenInfoResponse {
+                name: "".to_string(),
+                symbol: "STT".to_string(),
+                decimals: 6,
+                total_supply: Default::default(),
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
 }
 
 pub fn default_msg() -> InstantiateMsg {
     InstantiateMsg {
         pool: TEST_POOL.to_string(),
+        name: None,
+        symbol: None,
+        decimals: None,
     }
 }
 
@@ -91,4 +126,100 @@ fn success() {
             pool: api.addr_validate(TEST_POOL).unwrap()
         }
     );
+
+    assert_eq!(
+        MARKETING_INFO.load(deps.as_ref().storage).unwrap(),
+        MarketingInfoResponse {
+            project: Some(TEST_PROJECT.to_string()),
+            description: None,
+            marketing: Some(api.addr_validate(TEST_OWNER).unwrap()),
+            logo: Some(LogoInfo::Url(TEST_LOGO_URL.to_string())),
+        }
+    );
+    assert_eq!(
+        LOGO.load(deps.as_ref().storage).unwrap(),
+        Logo::Url(TEST_LOGO_URL.to_string())
+    );
+}
+
+#[test]
+fn success_with_overrides() {
+    let mut deps = mock_deps();
+    register_query_handlers(&mut deps, 150 * 86400);
+
+    let mut msg = default_msg();
+    msg.name = Some("Pylon TRT Pool Token".to_string());
+    msg.symbol = Some("bTRT".to_string());
+    msg.decimals = Some(8);
+    exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg).unwrap();
+
+    assert_eq!(
+        TOKEN_INFO.load(deps.as_ref().storage).unwrap(),
+        TokenInfo {
+            name: "Pylon TRT Pool Token".to_string(),
+            symbol: "bTRT".to_string(),
+            decimals: 8,
+            total_supply: Uint128::zero(),
+            mint: None
+        }
+    );
+}
+
+#[test]
+fn success_labels_period_in_days() {
+    let mut deps = mock_deps();
+    register_query_handlers(&mut deps, 45 * 86400);
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg(),
+    )
+    .unwrap();
+
+    let token_info = TOKEN_INFO.load(deps.as_ref().storage).unwrap();
+    assert_eq!(token_info.name, "Pylon bDP Token for Gateway TRT 45d Pool");
+    assert_eq!(token_info.symbol, "bTRTDP-45d");
+}
+
+fn register_reward_token_without_marketing(deps: &mut MockDeps) {
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_REWARD_TOKEN.to_string(),
+        Box::new(|x| match from_binary::<Cw20QueryMsg>(x).unwrap() {
+            Cw20QueryMsg::TokenInfo {} => to_binary(&TokenInfoResponse {
+                name: "".to_string(),
+                symbol: "TRT".to_string(),
+                decimals: 0,
+                total_supply: Default::default(),
+            }),
+            // cw20 without marketing extension fails to parse the query
+            _ => Err(StdError::parse_err("Cw20QueryMsg", "unknown variant")),
+        }),
+    );
+}
+
+#[test]
+fn success_without_reward_marketing() {
+    let mut deps = mock_deps();
+    register_query_handlers(&mut deps, 150 * 86400);
+    register_reward_token_without_marketing(&mut deps);
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg(),
+    )
+    .unwrap();
+
+    let api = deps.api;
+    assert_eq!(
+        MARKETING_INFO.load(deps.as_ref().storage).unwrap(),
+        MarketingInfoResponse {
+            project: None,
+            description: None,
+            marketing: Some(api.addr_validate(TEST_OWNER).unwrap()),
+            logo: None,
+        }
+    );
+    assert_eq!(LOGO.may_load(deps.as_ref().storage).unwrap(), None);
 }
@@ -0,0 +1,55 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::Api;
+use cw20::{Logo, LogoInfo, MarketingInfoResponse};
+use cw20_base::state::{LOGO, MARKETING_INFO};
+use pylon_gateway::pool_token_msg::MigrateMsg;
+
+use crate::entrypoints::migrate;
+use crate::testing::instantiate::{TEST_LOGO_URL, TEST_PROJECT};
+use crate::testing::{instantiate, mock_deps, TEST_OWNER};
+
+#[test]
+fn success_init_marketing() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    // instantiated before marketing support
+    MARKETING_INFO.remove(deps.as_mut().storage);
+    LOGO.remove(deps.as_mut().storage);
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let api = deps.api;
+    assert_eq!(
+        MARKETING_INFO.load(deps.as_ref().storage).unwrap(),
+        MarketingInfoResponse {
+            project: Some(TEST_PROJECT.to_string()),
+            description: None,
+            marketing: Some(api.addr_validate(TEST_OWNER).unwrap()),
+            logo: Some(LogoInfo::Url(TEST_LOGO_URL.to_string())),
+        }
+    );
+    assert_eq!(
+        LOGO.load(deps.as_ref().storage).unwrap(),
+        Logo::Url(TEST_LOGO_URL.to_string())
+    );
+}
+
+#[test]
+fn success_keep_marketing() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let mut marketing_info = MARKETING_INFO.load(deps.as_ref().storage).unwrap();
+    marketing_info.description = Some("updated".to_string());
+    MARKETING_INFO
+        .save(deps.as_mut().storage, &marketing_info)
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(
+        MARKETING_INFO.load(deps.as_ref().storage).unwrap(),
+        marketing_info
+    );
+}
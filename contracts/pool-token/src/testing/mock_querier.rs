@@ -67,12 +67,13 @@ impl CustomMockWasmQuerier {
     fn handle_query(&self, request: &QueryRequest<TerraQueryWrapper>) -> QuerierResult {
         match request {
             QueryRequest::Wasm(wasm_request) => match wasm_request {
-                WasmQuery::Smart { contract_addr, msg } => SystemResult::Ok(ContractResult::Ok(
+                WasmQuery::Smart { contract_addr, msg } => SystemResult::Ok(
+                    // handler errors are returned as errors of the queried contract
                     self.wasm_smart_query_handlers
                         .get(contract_addr.as_str())
                         .expect("wasm: smart query handler not found")(msg)
-                    .unwrap(),
-                )),
+                    .into(),
+                ),
                 WasmQuery::Raw { contract_addr, key } => SystemResult::Ok(ContractResult::Ok(
                     self.wasm_raw_query_handlers
                         .get(contract_addr.as_str())
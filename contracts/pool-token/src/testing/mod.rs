@@ -5,6 +5,7 @@ use crate::testing::mock_querier::{mock_dependencies, CustomMockWasmQuerier};
 
 mod executions;
 mod instantiate;
+mod migrate;
 mod mock_querier;
 mod queries;
 
@@ -14,6 +15,7 @@ const TEST_RECIPIENT: &str = "terra1e8ryd9ezefuucd4mje33zdms9m2s90m57878v9";
 
 const TEST_POOL: &str = "terra17lmam6zguazs5q5u6z5mmx76uj63gldnse2pdp";
 const TEST_REWARD_TOKEN: &str = "terra1757tkx08n0cqrw7p86ny9lnxsqeth0wgp0em95";
+const TEST_SHARE_TOKEN: &str = "terra199vw7724lzkwz6lf2hsx04lrxfkz09tg8dlp6r";
 
 type MockDeps = OwnedDeps<MockStorage, MockApi, CustomMockWasmQuerier>;
 
@@ -1,8 +1,10 @@
 use cosmwasm_std::testing::mock_env;
-use cosmwasm_std::{from_binary, to_binary, Uint128};
+use cosmwasm_std::{from_binary, from_slice, to_binary, Uint128};
 use cw20::{BalanceResponse, Cw20QueryMsg};
 use pylon_gateway::pool_msg::{QueryMsg as PoolQueryMsg, QueryMsg};
 use pylon_gateway::pool_resp::StakerResponse;
+use pylon_gateway::pool_resp_v2::StakerAtResponse;
+use pylon_gateway::pool_token_msg::QueryMsg as TokenQueryMsg;
 
 use crate::entrypoints::query;
 use crate::testing::{instantiate, mock_deps, TEST_POOL, TEST_SENDER};
@@ -34,6 +36,48 @@ fn success() {
         mock_env(),
         Cw20QueryMsg::Balance {
             address: TEST_SENDER.to_string(),
+        }
+        .into(),
+    )
+    .unwrap();
+    let response = from_binary::<BalanceResponse>(&response).unwrap();
+    assert_eq!(
+        response,
+        BalanceResponse {
+            balance: Uint128::from(BALANCE),
+        }
+    );
+}
+
+#[test]
+fn success_at() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const BALANCE: u128 = 1000;
+    const HEIGHT: u64 = 12345;
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_POOL.to_string(),
+        Box::new(|x| match from_binary::<PoolQueryMsg>(x).unwrap() {
+            QueryMsg::StakerAt { address, height } => match (address.as_str(), height) {
+                (TEST_SENDER, HEIGHT) => to_binary(&StakerAtResponse {
+                    address,
+                    height,
+                    staked: Uint128::from(BALANCE),
+                }),
+                _ => panic!("Unexpected staker address or height"),
+            },
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        TokenQueryMsg::BalanceAt {
+            address: TEST_SENDER.to_string(),
+            height: HEIGHT,
         },
     )
     .unwrap();
@@ -45,3 +89,25 @@ fn success() {
         }
     );
 }
+
+#[test]
+fn parse_flat_query() {
+    let msg: TokenQueryMsg =
+        from_slice(br#"{"balance_at":{"address":"sender","height":1}}"#).unwrap();
+    assert_eq!(
+        msg,
+        TokenQueryMsg::BalanceAt {
+            address: "sender".to_string(),
+            height: 1,
+        }
+    );
+
+    let msg: TokenQueryMsg = from_slice(br#"{"balance":{"address":"sender"}}"#).unwrap();
+    assert_eq!(
+        msg,
+        Cw20QueryMsg::Balance {
+            address: "sender".to_string(),
+        }
+        .into()
+    );
+}
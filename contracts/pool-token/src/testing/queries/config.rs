@@ -0,0 +1,20 @@
+use crate::entrypoints::query;
+use cosmwasm_std::from_binary;
+use cosmwasm_std::testing::mock_env;
+use pylon_gateway::pool_token_msg::{ConfigResponse, QueryMsg};
+
+use crate::testing::{instantiate, mock_deps, TEST_POOL};
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let response = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    assert_eq!(
+        from_binary::<ConfigResponse>(&response).unwrap(),
+        ConfigResponse {
+            pool: TEST_POOL.to_string(),
+        }
+    );
+}
@@ -1,3 +1,4 @@
 mod all_accounts;
 mod balance;
+mod config;
 mod token_info;
@@ -4,6 +4,8 @@ use cosmwasm_std::{from_binary, to_binary, Uint128};
 use cw20::{Cw20QueryMsg, TokenInfoResponse};
 use pylon_gateway::pool_msg::{QueryMsg as PoolQueryMsg, QueryMsg};
 use pylon_gateway::pool_resp::RewardResponse;
+use pylon_gateway::pool_resp_v2::TotalDepositAtResponse;
+use pylon_gateway::pool_token_msg::QueryMsg as TokenQueryMsg;
 
 use crate::testing::{instantiate, mock_deps, TEST_POOL};
 
@@ -25,7 +27,7 @@ fn success() {
         }),
     );
 
-    let response = query(deps.as_ref(), mock_env(), Cw20QueryMsg::TokenInfo {}).unwrap();
+    let response = query(deps.as_ref(), mock_env(), Cw20QueryMsg::TokenInfo {}.into()).unwrap();
     let response = from_binary::<TokenInfoResponse>(&response).unwrap();
     assert_eq!(
         response,
@@ -37,3 +39,32 @@ fn success() {
         }
     )
 }
+
+#[test]
+fn success_at() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const TOTAL_DEPOSIT_AMOUNT: u128 = 1000;
+    const HEIGHT: u64 = 12345;
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_POOL.to_string(),
+        Box::new(|x| match from_binary::<PoolQueryMsg>(x).unwrap() {
+            QueryMsg::TotalDepositAt { height: HEIGHT } => to_binary(&TotalDepositAtResponse {
+                height: HEIGHT,
+                total_deposit: Uint128::from(TOTAL_DEPOSIT_AMOUNT),
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        TokenQueryMsg::TokenInfoAt { height: HEIGHT },
+    )
+    .unwrap();
+    let response = from_binary::<TokenInfoResponse>(&response).unwrap();
+    assert_eq!(response.total_supply, Uint128::from(TOTAL_DEPOSIT_AMOUNT));
+}
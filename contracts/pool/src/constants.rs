@@ -3,7 +3,11 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // reply
 pub const INSTANTIATE_REPLY_ID: u64 = 1;
+pub const REPLACE_TOKEN_REPLY_ID: u64 = 2;
 
 // pagination
 pub const MAX_QUERY_LIMIT: u32 = 30;
 pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+
+// migration
+pub const MIGRATION_BATCH_LIMIT: usize = 100;
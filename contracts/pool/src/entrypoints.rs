@@ -3,17 +3,22 @@ use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
     to_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response,
-    StdError, SubMsg, Uint128, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
-use protobuf::Message;
-use pylon_gateway::pool_msg::{ConfigureMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use pylon_gateway::pause::{self, PauseUpdate};
+use pylon_gateway::pool_msg::{
+    ConfigureMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Role,
+};
 use pylon_gateway::pool_token_msg::InstantiateMsg as PoolTokenInitMsg;
+use pylon_gateway::reply::parse_reply_instantiate_data;
+use pylon_gateway::role::{self, Roles};
 
-use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION, INSTANTIATE_REPLY_ID};
+use crate::constants::{
+    CONTRACT_NAME, CONTRACT_VERSION, INSTANTIATE_REPLY_ID, REPLACE_TOKEN_REPLY_ID,
+};
 use crate::error::ContractError;
 use crate::querier::Querier;
-use crate::response::MsgInstantiateContractResponse;
 use crate::states::config::Config;
 use crate::states::reward::Reward;
 use crate::{executions, migrations, queries};
@@ -79,6 +84,9 @@ pub fn instantiate(
                 label: "".to_string(),
                 msg: to_binary(&PoolTokenInitMsg {
                     pool: env.contract.address.to_string(),
+                    name: msg.pool_token_name,
+                    symbol: msg.pool_token_symbol,
+                    decimals: msg.pool_token_decimals,
                 })?,
             }
             .into(),
@@ -133,10 +141,15 @@ pub fn execute(
                 })?,
                 funds: vec![],
             }))),
-        // internal
-        ExecuteMsg::DepositInternal { sender, amount } => {
-            executions::staking::deposit(deps, env, info, sender, amount)
+        ExecuteMsg::EmergencyWithdraw {} => {
+            executions::staking::emergency_withdraw(deps, env, info)
         }
+        // internal
+        ExecuteMsg::DepositInternal {
+            sender,
+            recipient,
+            amount,
+        } => executions::staking::deposit(deps, env, info, sender, recipient, amount),
         ExecuteMsg::WithdrawInternal { sender, amount } => {
             executions::staking::withdraw(deps, env, info, sender, amount)
         }
@@ -146,10 +159,33 @@ pub fn execute(
             recipient,
             amount,
         } => executions::staking::transfer(deps, env, info, owner, recipient, amount),
+        ExecuteMsg::BurnInternal { owner, amount } => {
+            executions::staking::burn(deps, env, info, owner, amount)
+        }
         // owner
         ExecuteMsg::Configure(msg) => {
             let config = Config::load(deps.storage)?;
-            if config.owner != info.sender {
+            // the owner holds every role. anything routing funds stays owner only
+            let role = match &msg {
+                ConfigureMsg::Config {
+                    share_token: None,
+                    reward_token: None,
+                    ..
+                } => Some(Role::ConfigAdmin),
+                ConfigureMsg::SubReward { .. } | ConfigureMsg::AddReward { .. } => {
+                    Some(Role::RewardManager)
+                }
+                ConfigureMsg::Pause { .. } => Some(Role::Guardian),
+                ConfigureMsg::Config { .. }
+                | ConfigureMsg::AddPoolToken { .. }
+                | ConfigureMsg::AttachPoolToken { .. }
+                | ConfigureMsg::ProposeOwner { .. }
+                | ConfigureMsg::CancelOwnerProposal { .. }
+                | ConfigureMsg::AssignRole { .. }
+                | ConfigureMsg::Emergency { .. } => None,
+            };
+            let roles = Roles::<Role>::load(deps.storage)?;
+            if config.owner != info.sender && !roles.is_granted(role.as_ref(), &info.sender) {
                 return Err(ContractError::Unauthorized {
                     action: "configure".to_string(),
                     expected: config.owner.to_string(),
@@ -159,7 +195,6 @@ pub fn execute(
 
             match msg {
                 ConfigureMsg::Config {
-                    owner,
                     share_token,
                     reward_token,
                     claim_time,
@@ -170,7 +205,6 @@ pub fn execute(
                     deps,
                     env,
                     info,
-                    owner,
                     share_token,
                     reward_token,
                     claim_time,
@@ -184,7 +218,21 @@ pub fn execute(
                 ConfigureMsg::AddReward { amount } => {
                     executions::config::adjust_reward(deps, env, amount, false)
                 }
-                ConfigureMsg::AddPoolToken { code_id } => {
+                ConfigureMsg::AddPoolToken {
+                    code_id,
+                    name,
+                    symbol,
+                    decimals,
+                    replace,
+                } => {
+                    if !replace && config.token != Addr::unchecked("".to_string()) {
+                        return Err(ContractError::Unauthorized {
+                            action: "register_token".to_string(),
+                            expected: "".to_string(),
+                            actual: config.token.to_string(),
+                        });
+                    }
+
                     Ok(Response::new().add_submessage(SubMsg {
                         // Create DP token
                         msg: WasmMsg::Instantiate {
@@ -194,48 +242,78 @@ pub fn execute(
                             label: "".to_string(),
                             msg: to_binary(&PoolTokenInitMsg {
                                 pool: env.contract.address.to_string(),
+                                name,
+                                symbol,
+                                decimals,
                             })?,
                         }
                         .into(),
                         gas_limit: None,
-                        id: INSTANTIATE_REPLY_ID,
+                        id: if replace {
+                            REPLACE_TOKEN_REPLY_ID
+                        } else {
+                            INSTANTIATE_REPLY_ID
+                        },
                         reply_on: ReplyOn::Success,
                     }))
                 }
+                ConfigureMsg::AttachPoolToken { address } => {
+                    let token = deps.api.addr_validate(address.as_str())?;
+                    // a token reading balances of another pool must not be registered
+                    let token_config =
+                        Querier::new(&deps.querier).load_pool_token_config(&token)?;
+                    if token_config.pool != env.contract.address.as_str() {
+                        return Err(ContractError::InvalidPoolToken {
+                            pool: token_config.pool,
+                        });
+                    }
+                    executions::config::register_token(deps, env, token, true)
+                }
+                ConfigureMsg::ProposeOwner { owner } => {
+                    Ok(role::propose_owner::<Role>(deps.storage, deps.api, owner)?)
+                }
+                ConfigureMsg::CancelOwnerProposal {} => {
+                    Ok(role::cancel_owner_proposal::<Role>(deps.storage)?)
+                }
+                ConfigureMsg::AssignRole { role, address } => {
+                    Ok(role::assign_role(deps.storage, deps.api, role, address)?)
+                }
+                ConfigureMsg::Pause {
+                    deposit,
+                    withdraw,
+                    claim,
+                } => Ok(pause::update(
+                    deps.storage,
+                    &info.sender,
+                    env.block.time.seconds(),
+                    PauseUpdate {
+                        deposit,
+                        withdraw,
+                        claim,
+                        ..PauseUpdate::default()
+                    },
+                )?),
+                ConfigureMsg::Emergency { emergency } => {
+                    executions::pause::update_emergency(deps, env, info, emergency)
+                }
             }
         }
+        ExecuteMsg::AcceptOwner {} => executions::config::accept_owner(deps, env, info),
     }
 }
 
 #[allow(dead_code)]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> executions::ExecuteResult {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> executions::ExecuteResult {
     match msg.id {
-        INSTANTIATE_REPLY_ID => {
-            // get new token's contract address
-            let res: MsgInstantiateContractResponse = Message::parse_from_bytes(
-                msg.result.unwrap().data.unwrap().as_slice(),
-            )
-            .map_err(|_| {
-                ContractError::Std(StdError::parse_err(
-                    "MsgInstantiateContractResponse",
-                    "failed to parse data",
-                ))
-            })?;
-            let token_addr = Addr::unchecked(res.get_contract_address());
+        INSTANTIATE_REPLY_ID | REPLACE_TOKEN_REPLY_ID => {
+            let replace = msg.id == REPLACE_TOKEN_REPLY_ID;
 
-            let mut config = Config::load(deps.storage)?;
-            if config.token != Addr::unchecked("".to_string()) {
-                return Err(ContractError::Unauthorized {
-                    action: "register_token".to_string(),
-                    expected: "".to_string(),
-                    actual: config.token.to_string(),
-                });
-            }
-            config.token = token_addr;
-            Config::save(deps.storage, &config)?;
+            // get new token's contract address
+            let res = parse_reply_instantiate_data(msg)?;
+            let token_addr = deps.api.addr_validate(res.contract_address.as_str())?;
 
-            Ok(Response::new().add_attribute("action", "register_token"))
+            executions::config::register_token(deps, env, token_addr, replace)
         }
         _ => Err(ContractError::InvalidReplyId { id: msg.id }),
     }
@@ -266,12 +344,27 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> queries::QueryResult {
             limit,
             order,
         } => queries::user::query_stakers(deps, env, start_after, limit, order),
+        QueryMsg::Accounts { start_after, limit } => {
+            queries::user::query_accounts(deps, env, start_after, limit)
+        }
+        QueryMsg::StakerAt { address, height } => {
+            queries::user::query_staker_at(deps, env, address, height)
+        }
+        QueryMsg::TotalDepositAt { height } => {
+            queries::reward::query_total_deposit_at(deps, env, height)
+        }
+        QueryMsg::TokenHistory {} => queries::config::query_token_history(deps, env),
+        QueryMsg::Roles {} => queries::config::query_roles(deps, env),
+        QueryMsg::Pause {} => queries::config::query_pause(deps, env),
+        QueryMsg::PauseHistory { start_after, limit } => {
+            queries::config::query_pause_history(deps, env, start_after, limit)
+        }
     }
 }
 
 #[allow(dead_code)]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> migrations::MigrateResult {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> migrations::MigrateResult {
     match get_contract_version(deps.storage) {
         Ok(ContractVersion { contract, version }) => {
             if contract != CONTRACT_NAME {
@@ -283,10 +376,15 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> migrations::Migrate
             }
 
             match version.as_str() {
-                "0.1.1" => Ok(Response::new()), // TODO: do it next time
+                "0.1.1" => migrations::staker_index::migrate(deps, env),
                 _ => Err(ContractError::InvalidContractVersionForMigration {}),
             }
         }
-        Err(_) => migrations::legacy::migrate(deps, env),
+        Err(_) => {
+            migrations::legacy::migrate(deps.branch(), env.clone())?;
+            // an unfinished staker index migration resumes from 0.1.1
+            set_contract_version(deps.storage, CONTRACT_NAME, "0.1.1")?;
+            migrations::staker_index::migrate(deps, env)
+        }
     }
 }
@@ -1,4 +1,7 @@
 use cosmwasm_std::{OverflowError, StdError, Uint128};
+use pylon_gateway::pause::PauseError;
+use pylon_gateway::reply::ParseReplyError;
+use pylon_gateway::role::RoleError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -9,6 +12,9 @@ pub enum ContractError {
     #[error("{0}")]
     Overflow(#[from] OverflowError),
 
+    #[error("{0}")]
+    ParseReply(#[from] ParseReplyError),
+
     #[error(
         "Gateway/Pool: unauthorized (action: {action:?}, expected: {expected:?}, actual: {actual:?})"
     )]
@@ -45,12 +51,50 @@ pub enum ContractError {
     #[error("Gateway/Pool: transfer amount exceeds balance. (balance: {amount:?})")]
     TransferAmountExceeded { amount: Uint128 },
 
+    #[error("Gateway/Pool: transfer to the owner itself is not allowed.")]
+    NotAllowSelfTransfer {},
+
     #[error("Gateway/Pool: sale finished. (now: {now:?}, finished: {finished:?})")]
     SaleFinished { now: u64, finished: u64 },
 
     #[error("Gateway/Pool: withdraw strategy length exceeds limit. (limit: {limit:?}, length: {length:?})")]
     WithdrawStrategyLengthExceeded { limit: usize, length: usize },
 
+    #[error("Gateway/Pool: nothing to withdraw.")]
+    NothingToWithdraw {},
+
+    #[error("Gateway/Pool: paused. (action: {action:?})")]
+    Paused { action: String },
+
+    #[error("Gateway/Pool: pool token is bound to another pool. (pool: {pool:?})")]
+    InvalidPoolToken { pool: String },
+
     #[error("Gateway/Pool: invalid contract version for migration.")]
     InvalidContractVersionForMigration {},
 }
+
+impl From<RoleError> for ContractError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::Std(err) => ContractError::Std(err),
+            RoleError::Unauthorized {
+                action,
+                expected,
+                actual,
+            } => ContractError::Unauthorized {
+                action,
+                expected,
+                actual,
+            },
+        }
+    }
+}
+
+impl From<PauseError> for ContractError {
+    fn from(err: PauseError) -> Self {
+        match err {
+            PauseError::Std(err) => ContractError::Std(err),
+            PauseError::Paused { action } => ContractError::Paused { action },
+        }
+    }
+}
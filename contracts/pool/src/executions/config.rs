@@ -1,15 +1,18 @@
-use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{attr, Addr, Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+use pylon_gateway::pool_msg::Role;
+use pylon_gateway::role;
 use pylon_gateway::time_range::TimeRange;
 use std::cmp::max;
 
+use crate::error::ContractError;
 use crate::states::config::Config;
+use crate::states::token::PoolToken;
 
 #[allow(clippy::too_many_arguments)]
 pub fn update(
     deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
-    owner: Option<String>,
     share_token: Option<String>,
     reward_token: Option<String>,
     claim_time: Option<Vec<TimeRange>>,
@@ -19,10 +22,6 @@ pub fn update(
 ) -> super::ExecuteResult {
     let mut config = Config::load(deps.storage)?;
 
-    // authorization
-    if let Some(v) = owner {
-        config.owner = deps.api.addr_validate(v.as_str())?;
-    }
     // tokens
     if let Some(v) = share_token {
         config.share_token = deps.api.addr_validate(v.as_str())?;
@@ -84,3 +83,44 @@ pub fn adjust_reward(
 
     Ok(response)
 }
+
+// the pool stops honouring internal messages of the replaced token
+pub fn register_token(deps: DepsMut, env: Env, token: Addr, replace: bool) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    if !replace && config.token != Addr::unchecked("".to_string()) {
+        return Err(ContractError::Unauthorized {
+            action: "register_token".to_string(),
+            expected: "".to_string(),
+            actual: config.token.to_string(),
+        });
+    }
+
+    PoolToken::register(
+        deps.storage,
+        &config.token,
+        &token,
+        env.block.time.seconds(),
+    )?;
+    let previous = config.token;
+    config.token = token;
+    Config::save(deps.storage, &config)?;
+
+    let mut resp = Response::new().add_attribute("action", "register_token");
+    if replace {
+        resp = resp.add_attributes(vec![
+            attr("token", config.token.to_string()),
+            attr("replaced", previous.to_string()),
+        ]);
+    }
+
+    Ok(resp)
+}
+
+pub fn accept_owner(deps: DepsMut, _env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    let resp = role::accept_owner::<Role>(deps.storage, &info.sender, &config.owner)?;
+    config.owner = info.sender;
+    Config::save(deps.storage, &config)?;
+
+    Ok(resp)
+}
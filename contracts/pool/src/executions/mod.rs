@@ -8,6 +8,7 @@ use crate::error::ContractError;
 use crate::states::config::Config;
 
 pub mod config;
+pub mod pause;
 pub mod staking;
 
 pub type ExecuteResult = Result<Response, ContractError>;
@@ -19,7 +20,7 @@ pub fn receive(
     cw20_msg: Cw20ReceiveMsg,
 ) -> ExecuteResult {
     match from_binary(&cw20_msg.msg) {
-        Ok(Cw20HookMsg::Deposit {}) => {
+        Ok(Cw20HookMsg::Deposit { recipient }) => {
             let config = Config::load(deps.storage)?;
             if config.share_token != info.sender {
                 return Err(ContractError::Unauthorized {
@@ -29,11 +30,17 @@ pub fn receive(
                 });
             }
 
+            let recipient = recipient
+                .map(|x| deps.api.addr_validate(x.as_str()).map(|x| x.to_string()))
+                .transpose()?;
+
             Ok(Response::new()
                 .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
                     contract_addr: env.contract.address.to_string(),
                     msg: to_binary(&ExecuteMsg::Update {
-                        target: Option::Some(cw20_msg.sender.clone()),
+                        target: Option::Some(
+                            recipient.clone().unwrap_or_else(|| cw20_msg.sender.clone()),
+                        ),
                     })?,
                     funds: vec![],
                 }))
@@ -41,6 +48,7 @@ pub fn receive(
                     contract_addr: env.contract.address.to_string(),
                     msg: to_binary(&ExecuteMsg::DepositInternal {
                         sender: cw20_msg.sender,
+                        recipient,
                         amount: cw20_msg.amount,
                     })?,
                     funds: vec![],
@@ -0,0 +1,26 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use pylon_gateway::pause::{self, PauseUpdate};
+
+pub fn update_emergency(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    emergency: bool,
+) -> super::ExecuteResult {
+    let events = pause::apply(
+        deps.storage,
+        &info.sender,
+        env.block.time.seconds(),
+        PauseUpdate {
+            emergency: Some(emergency),
+            ..PauseUpdate::default()
+        },
+    )?;
+
+    let mut resp = Response::new().add_attribute("action", "update_emergency");
+    if !events.is_empty() {
+        resp = resp.add_attribute("emergency", emergency.to_string());
+    }
+
+    Ok(resp)
+}
@@ -3,6 +3,8 @@ use cosmwasm_std::{
     StdResult, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
+use pylon_gateway::pause::Pause;
+use pylon_gateway::pool_msg::ExecuteMsg;
 use std::cmp::{max, min};
 
 use crate::error::ContractError;
@@ -66,6 +68,7 @@ pub fn deposit(
     env: Env,
     info: MessageInfo,
     sender: String,
+    recipient: Option<String>,
     amount: Uint128,
 ) -> super::ExecuteResult {
     if env.contract.address != info.sender {
@@ -77,26 +80,32 @@ pub fn deposit(
     }
 
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_deposit()?;
     config.check_deposit_time(&env)?;
 
+    let owner = deps
+        .api
+        .addr_canonicalize(recipient.as_ref().unwrap_or(&sender).as_str())?;
     let mut reward = Reward::load(deps.storage)?;
-    let mut user = User::load(deps.storage, &deps.api.addr_canonicalize(sender.as_str())?);
+    let mut user = User::load(deps.storage, &owner);
 
     reward.total_deposit += amount;
     user.amount += amount;
 
     Reward::save(deps.storage, &reward)?;
-    User::save(
-        deps.storage,
-        &deps.api.addr_canonicalize(sender.as_str())?,
-        &user,
-    )
-    .unwrap();
+    Reward::save_checkpoint(deps.storage, env.block.height, &reward.total_deposit)?;
+    User::save(deps.storage, &owner, &user).unwrap();
+    User::save_checkpoint(deps.storage, &owner, env.block.height, &user.amount)?;
 
-    Ok(Response::new()
+    let mut resp = Response::new()
         .add_attribute("action", "deposit")
         .add_attribute("sender", sender)
-        .add_attribute("deposit_amount", amount.to_string()))
+        .add_attribute("deposit_amount", amount.to_string());
+    if let Some(recipient) = recipient {
+        resp = resp.add_attribute("recipient", recipient);
+    }
+
+    Ok(resp)
 }
 
 pub fn withdraw(
@@ -115,6 +124,7 @@ pub fn withdraw(
     }
 
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_withdraw()?;
     config.check_withdraw_time(&env)?;
 
     let owner = deps.api.addr_canonicalize(sender.as_str())?;
@@ -129,7 +139,9 @@ pub fn withdraw(
     user.amount -= amount;
 
     Reward::save(deps.storage, &reward)?;
+    Reward::save_checkpoint(deps.storage, env.block.height, &reward.total_deposit)?;
     User::save(deps.storage, &owner, &user)?;
+    User::save_checkpoint(deps.storage, &owner, env.block.height, &user.amount)?;
 
     Ok(Response::new()
         .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -146,6 +158,48 @@ pub fn withdraw(
         .add_attribute("withdraw_amount", amount.to_string()))
 }
 
+// skips Update, so the stake leaves without touching reward math
+pub fn emergency_withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    let pause = Pause::load(deps.storage)?;
+    if !pause.emergency {
+        pause.check_withdraw()?;
+        config.check_withdraw_time(&env)?;
+    }
+
+    let owner = deps.api.addr_canonicalize(info.sender.as_str())?;
+    let mut reward = Reward::load(deps.storage)?;
+    let mut user = User::load(deps.storage, &owner);
+    let amount = user.amount;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+
+    let forfeited = user.reward;
+    reward.total_deposit -= amount;
+    user.amount = Uint128::zero();
+    user.reward = Uint128::zero();
+
+    Reward::save(deps.storage, &reward)?;
+    Reward::save_checkpoint(deps.storage, env.block.height, &reward.total_deposit)?;
+    User::save(deps.storage, &owner, &user)?;
+    User::save_checkpoint(deps.storage, &owner, env.block.height, &user.amount)?;
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.share_token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }))
+        .add_attribute("action", "emergency_withdraw")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("withdraw_amount", amount.to_string())
+        .add_attribute("forfeited_reward", forfeited.to_string()))
+}
+
 pub fn claim(deps: DepsMut, env: Env, info: MessageInfo, sender: String) -> super::ExecuteResult {
     if env.contract.address != info.sender {
         return Err(ContractError::Unauthorized {
@@ -156,6 +210,7 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo, sender: String) -> supe
     }
 
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_claim()?;
     config.check_claim_time(&env)?;
 
     let owner = deps.api.addr_canonicalize(sender.as_str())?;
@@ -182,7 +237,7 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo, sender: String) -> supe
 
 pub fn transfer(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     owner: String,
     recipient: String,
@@ -205,27 +260,84 @@ pub fn transfer(
         });
     }
 
-    if owner != recipient {
-        let owner_addr = deps.api.addr_canonicalize(owner.as_str())?;
-        let recipient_addr = deps.api.addr_canonicalize(recipient.as_str())?;
-
-        let mut owner = User::load(deps.storage, &owner_addr);
-        let mut recipient = User::load(deps.storage, &recipient_addr);
+    if owner == recipient {
+        return Err(ContractError::NotAllowSelfTransfer {});
+    }
 
-        if owner.amount < amount {
-            return Err(ContractError::TransferAmountExceeded { amount });
-        }
+    let owner_addr = deps.api.addr_canonicalize(owner.as_str())?;
+    let recipient_addr = deps.api.addr_canonicalize(recipient.as_str())?;
 
-        owner.amount -= amount;
-        recipient.amount += amount;
+    let mut owner = User::load(deps.storage, &owner_addr);
+    let mut recipient = User::load(deps.storage, &recipient_addr);
 
-        User::save(deps.storage, &owner_addr, &owner)?;
-        User::save(deps.storage, &recipient_addr, &recipient)?;
+    if owner.amount < amount {
+        return Err(ContractError::TransferAmountExceeded { amount });
     }
 
+    owner.amount -= amount;
+    recipient.amount += amount;
+
+    User::save(deps.storage, &owner_addr, &owner)?;
+    User::save_checkpoint(deps.storage, &owner_addr, env.block.height, &owner.amount)?;
+    User::save(deps.storage, &recipient_addr, &recipient)?;
+    User::save_checkpoint(
+        deps.storage,
+        &recipient_addr,
+        env.block.height,
+        &recipient.amount,
+    )?;
+
     Ok(Response::new().add_attributes(vec![attr("action", "transfer_internal")]))
 }
 
+pub fn burn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    amount: Uint128,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if config.token == Addr::unchecked("".to_string()) {
+        return Err(ContractError::Unauthorized {
+            action: "burn_internal".to_string(),
+            expected: "<not assigned>".to_string(),
+            actual: info.sender.to_string(),
+        });
+    }
+
+    if config.token != info.sender {
+        return Err(ContractError::Unauthorized {
+            action: "burn_internal".to_string(),
+            expected: config.token.to_string(),
+            actual: info.sender.to_string(),
+        });
+    }
+
+    // burning withdraws the principal to the holder
+    Ok(Response::new()
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::Update {
+                target: Some(owner.clone()),
+            })?,
+            funds: vec![],
+        }))
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::WithdrawInternal {
+                sender: owner.clone(),
+                amount,
+            })?,
+            funds: vec![],
+        }))
+        .add_attributes(vec![
+            attr("action", "burn_internal"),
+            attr("owner", owner),
+            attr("amount", amount.to_string()),
+        ]))
+}
+
 pub fn calculate_reward_per_token(
     config: &Config,
     reward: &Reward,
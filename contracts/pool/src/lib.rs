@@ -8,7 +8,6 @@ pub mod states;
 mod constants;
 mod error;
 mod querier;
-mod response;
 
 #[cfg(test)]
 mod testing;
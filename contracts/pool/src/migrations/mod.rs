@@ -3,5 +3,6 @@ use cosmwasm_std::Response;
 use crate::error::ContractError;
 
 pub mod legacy;
+pub mod staker_index;
 
 pub type MigrateResult = Result<Response, ContractError>;
@@ -0,0 +1,51 @@
+use cosmwasm_std::{attr, CanonicalAddr, DepsMut, Env, Order, Response};
+use cosmwasm_storage::{ReadonlyBucket, ReadonlySingleton, Singleton};
+use cw2::set_contract_version;
+use pylon_utils::range::calc_range_start_addr;
+
+use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION, MIGRATION_BATCH_LIMIT};
+use crate::states::reward::Reward;
+use crate::states::user::User;
+use crate::states::{KEY_STAKER_INDEX_CURSOR, PREFIX_USER};
+
+// builds the staker index and the first checkpoints from the stakes stored before they existed.
+// runs in batches. the last indexed staker is kept as a cursor and the next migration resumes from it
+pub fn migrate(deps: DepsMut, env: Env) -> super::MigrateResult {
+    let cursor = ReadonlySingleton::<CanonicalAddr>::new(deps.storage, KEY_STAKER_INDEX_CURSOR)
+        .may_load()?;
+    if cursor.is_none() {
+        let reward = Reward::load(deps.storage)?;
+        Reward::save_checkpoint(deps.storage, env.block.height, &reward.total_deposit)?;
+    }
+
+    let start = calc_range_start_addr(cursor);
+    let mut users: Vec<(CanonicalAddr, User)> =
+        ReadonlyBucket::<User>::new(deps.storage, PREFIX_USER)
+            .range(start.as_deref(), None, Order::Ascending)
+            .take(MIGRATION_BATCH_LIMIT + 1)
+            .map(|item| {
+                let (k, v) = item.unwrap();
+                (CanonicalAddr::from(k.as_slice()), v)
+            })
+            .collect();
+    let completed = users.len() <= MIGRATION_BATCH_LIMIT;
+    users.truncate(MIGRATION_BATCH_LIMIT);
+
+    for (owner, user) in users.iter() {
+        User::index_staker(deps.storage, owner, user)?;
+        User::save_checkpoint(deps.storage, owner, env.block.height, &user.amount)?;
+    }
+
+    if completed {
+        Singleton::<CanonicalAddr>::new(deps.storage, KEY_STAKER_INDEX_CURSOR).remove();
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    } else {
+        let (last, _) = users.last().unwrap();
+        Singleton::<CanonicalAddr>::new(deps.storage, KEY_STAKER_INDEX_CURSOR).save(last)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("indexed_stakers", users.len().to_string()),
+        attr("completed", completed.to_string()),
+    ]))
+}
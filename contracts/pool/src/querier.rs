@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
 use cw20::{Cw20QueryMsg, TokenInfoResponse};
+use pylon_gateway::pool_token_msg::{ConfigResponse, QueryMsg};
 
 pub struct Querier<'a> {
     querier: &'a QuerierWrapper<'a>,
@@ -17,4 +18,12 @@ impl Querier<'_> {
 
         Ok(token_info)
     }
+
+    pub fn load_pool_token_config(&self, address: &Addr) -> StdResult<ConfigResponse> {
+        let config: ConfigResponse = self
+            .querier
+            .query_wasm_smart(address, &QueryMsg::Config {})?;
+
+        Ok(config)
+    }
 }
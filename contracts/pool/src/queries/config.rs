@@ -1,8 +1,13 @@
 use cosmwasm_std::{to_binary, Deps, Env, Uint128};
+use pylon_gateway::pause::{Pause, PauseEvent};
+use pylon_gateway::pool_msg::Role;
 use pylon_gateway::pool_resp;
 use pylon_gateway::pool_resp_v2;
+use pylon_gateway::role::Roles;
 
+use crate::constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
 use crate::states::config::Config;
+use crate::states::token::PoolToken;
 
 pub fn query_config(deps: Deps, _env: Env) -> super::QueryResult {
     let config = Config::load(deps.storage)?;
@@ -49,3 +54,63 @@ pub fn query_config_v2(deps: Deps, _env: Env) -> super::QueryResult {
         reward_distribution_time: config.reward_distribution_time,
     })?)
 }
+
+pub fn query_token_history(deps: Deps, _env: Env) -> super::QueryResult {
+    Ok(to_binary(&pool_resp_v2::TokenHistoryResponse {
+        tokens: PoolToken::load_history(deps.storage)
+            .into_iter()
+            .map(|token| pool_resp_v2::PoolTokenResponse {
+                address: token.address.to_string(),
+                registered_at: token.registered_at,
+                deactivated_at: token.deactivated_at,
+            })
+            .collect(),
+    })?)
+}
+
+pub fn query_roles(deps: Deps, _env: Env) -> super::QueryResult {
+    let config = Config::load(deps.storage)?;
+    let roles = Roles::<Role>::load(deps.storage)?;
+    let holder = |role: Role| roles.holder(&role).map(|x| x.to_string());
+
+    Ok(to_binary(&pool_resp_v2::RolesResponse {
+        owner: config.owner.to_string(),
+        pending_owner: roles.pending_owner.as_ref().map(|x| x.to_string()),
+        config_admin: holder(Role::ConfigAdmin),
+        reward_manager: holder(Role::RewardManager),
+        guardian: holder(Role::Guardian),
+    })?)
+}
+
+pub fn query_pause(deps: Deps, _env: Env) -> super::QueryResult {
+    let pause = Pause::load(deps.storage)?;
+
+    Ok(to_binary(&pool_resp_v2::PauseResponse {
+        deposit: pause.deposit,
+        withdraw: pause.withdraw,
+        claim: pause.claim,
+        emergency: pause.emergency,
+    })?)
+}
+
+pub fn query_pause_history(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> super::QueryResult {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    Ok(to_binary(&pool_resp_v2::PauseHistoryResponse {
+        events: PauseEvent::load_range(deps.storage, start_after, limit)?
+            .into_iter()
+            .map(|(id, event)| pool_resp_v2::PauseEventResponse {
+                id,
+                action: event.action,
+                paused: event.paused,
+                sender: event.sender.to_string(),
+                time: event.time,
+            })
+            .collect(),
+    })?)
+}
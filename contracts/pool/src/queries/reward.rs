@@ -1,5 +1,6 @@
 use cosmwasm_std::{to_binary, Deps, Env};
 use pylon_gateway::pool_resp::RewardResponse;
+use pylon_gateway::pool_resp_v2::TotalDepositAtResponse;
 
 use crate::states::reward::Reward;
 
@@ -11,3 +12,10 @@ pub fn query_reward(deps: Deps, _env: Env) -> super::QueryResult {
         last_update_time: reward.last_update_time,
     })?)
 }
+
+pub fn query_total_deposit_at(deps: Deps, _env: Env, height: u64) -> super::QueryResult {
+    Ok(to_binary(&TotalDepositAtResponse {
+        height,
+        total_deposit: Reward::load_total_deposit_at(deps.storage, height)?,
+    })?)
+}
@@ -1,6 +1,6 @@
 use cosmwasm_bignumber::Uint256;
-use cosmwasm_std::{to_binary, Deps, Env};
-use pylon_gateway::pool_resp;
+use cosmwasm_std::{to_binary, Deps, Env, StdResult};
+use pylon_gateway::{pool_resp, pool_resp_v2};
 use pylon_utils::common::OrderBy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -135,3 +135,30 @@ pub fn query_stakers(
 
     Ok(to_binary(&pool_resp::StakersResponse { stakers })?)
 }
+
+pub fn query_accounts(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> super::QueryResult {
+    let start_after = start_after
+        .map(|x| deps.api.addr_canonicalize(x.as_str()))
+        .transpose()?;
+    let accounts = User::load_staker_range(deps.storage, start_after, limit)
+        .iter()
+        .map(|address| deps.api.addr_humanize(address).map(|x| x.to_string()))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    Ok(to_binary(&pool_resp_v2::AccountsResponse { accounts })?)
+}
+
+pub fn query_staker_at(deps: Deps, _env: Env, address: String, height: u64) -> super::QueryResult {
+    let user_addr = deps.api.addr_canonicalize(address.as_str())?;
+
+    Ok(to_binary(&pool_resp_v2::StakerAtResponse {
+        address,
+        height,
+        staked: User::load_amount_at(deps.storage, &user_addr, height)?,
+    })?)
+}
@@ -1,7 +1,13 @@
 pub mod config;
 pub mod reward;
+pub mod token;
 pub mod user;
 
 pub static KEY_CONFIG: &[u8] = b"config";
 pub static KEY_REWARD: &[u8] = b"reward";
+pub static KEY_TOKEN_HISTORY: &[u8] = b"token-history";
 pub static PREFIX_USER: &[u8] = b"user";
+pub static PREFIX_STAKER_INDEX: &[u8] = b"index-staker";
+pub static PREFIX_USER_CHECKPOINT: &[u8] = b"checkpoint-user";
+pub static PREFIX_TOTAL_DEPOSIT_CHECKPOINT: &[u8] = b"checkpoint-total-deposit";
+pub static KEY_STAKER_INDEX_CURSOR: &[u8] = b"migration-staker-index-cursor";
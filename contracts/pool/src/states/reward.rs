@@ -1,5 +1,5 @@
-use cosmwasm_std::{Decimal, StdResult, Storage, Uint128};
-use cosmwasm_storage::{ReadonlySingleton, Singleton};
+use cosmwasm_std::{Decimal, Order, StdResult, Storage, Uint128};
+use cosmwasm_storage::{Bucket, ReadonlyBucket, ReadonlySingleton, Singleton};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -18,4 +18,26 @@ impl Reward {
     pub fn save(storage: &mut dyn Storage, reward: &Self) -> StdResult<()> {
         Singleton::<Reward>::new(storage, super::KEY_REWARD).save(reward)
     }
+
+    pub fn save_checkpoint(
+        storage: &mut dyn Storage,
+        height: u64,
+        total_deposit: &Uint128,
+    ) -> StdResult<()> {
+        Bucket::<Uint128>::new(storage, super::PREFIX_TOTAL_DEPOSIT_CHECKPOINT)
+            .save(&height.to_be_bytes(), total_deposit)
+    }
+
+    // deposits never checkpointed at or before the height are reported as zero
+    pub fn load_total_deposit_at(storage: &dyn Storage, height: u64) -> StdResult<Uint128> {
+        ReadonlyBucket::<Uint128>::new(storage, super::PREFIX_TOTAL_DEPOSIT_CHECKPOINT)
+            .range(
+                None,
+                Some(&height.saturating_add(1).to_be_bytes()),
+                Order::Descending,
+            )
+            .next()
+            .map(|item| item.map(|(_, total_deposit)| total_deposit))
+            .unwrap_or_else(|| Ok(Uint128::zero()))
+    }
 }
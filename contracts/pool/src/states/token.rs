@@ -0,0 +1,47 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cosmwasm_storage::{ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoolToken {
+    pub address: Addr,
+    pub registered_at: u64,
+    pub deactivated_at: Option<u64>,
+}
+
+impl PoolToken {
+    pub fn load_history(storage: &dyn Storage) -> Vec<Self> {
+        ReadonlySingleton::<Vec<Self>>::new(storage, super::KEY_TOKEN_HISTORY)
+            .may_load()
+            .unwrap()
+            .unwrap_or_default()
+    }
+
+    // deactivates the previous token, which may predate the history
+    pub fn register(
+        storage: &mut dyn Storage,
+        previous: &Addr,
+        token: &Addr,
+        now: u64,
+    ) -> StdResult<()> {
+        let mut history = Self::load_history(storage);
+        if history.is_empty() && previous != &Addr::unchecked("") {
+            history.push(PoolToken {
+                address: previous.clone(),
+                registered_at: 0,
+                deactivated_at: None,
+            });
+        }
+        if let Some(last) = history.last_mut() {
+            last.deactivated_at = Some(now);
+        }
+        history.push(PoolToken {
+            address: token.clone(),
+            registered_at: now,
+            deactivated_at: None,
+        });
+
+        Singleton::<Vec<Self>>::new(storage, super::KEY_TOKEN_HISTORY).save(&history)
+    }
+}
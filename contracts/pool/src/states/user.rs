@@ -1,4 +1,4 @@
-use cosmwasm_std::{CanonicalAddr, Decimal, StdResult, Storage, Uint128};
+use cosmwasm_std::{CanonicalAddr, Decimal, Order, StdResult, Storage, Uint128};
 use cosmwasm_storage::{Bucket, ReadonlyBucket};
 use pylon_utils::common::OrderBy;
 use pylon_utils::range::{calc_range_end_addr, calc_range_start_addr};
@@ -44,10 +44,72 @@ impl User {
     }
 
     pub fn save(storage: &mut dyn Storage, owner: &CanonicalAddr, user: &Self) -> StdResult<()> {
-        Bucket::<User>::new(storage, super::PREFIX_USER).save(owner.as_slice(), user)
+        Bucket::<User>::new(storage, super::PREFIX_USER).save(owner.as_slice(), user)?;
+        Self::index_staker(storage, owner, user)
     }
 
     pub fn remove(storage: &mut dyn Storage, owner: &CanonicalAddr) {
-        Bucket::<User>::new(storage, super::PREFIX_USER).remove(owner.as_slice())
+        Bucket::<User>::new(storage, super::PREFIX_USER).remove(owner.as_slice());
+        Bucket::<bool>::new(storage, super::PREFIX_STAKER_INDEX).remove(owner.as_slice())
+    }
+
+    // keeps only accounts with a non-zero stake in the index
+    pub fn index_staker(
+        storage: &mut dyn Storage,
+        owner: &CanonicalAddr,
+        user: &Self,
+    ) -> StdResult<()> {
+        let mut index = Bucket::<bool>::new(storage, super::PREFIX_STAKER_INDEX);
+        if user.amount.is_zero() {
+            index.remove(owner.as_slice());
+            Ok(())
+        } else {
+            index.save(owner.as_slice(), &true)
+        }
+    }
+
+    pub fn load_staker_range(
+        storage: &dyn Storage,
+        start_after: Option<CanonicalAddr>,
+        limit: Option<u32>,
+    ) -> Vec<CanonicalAddr> {
+        let start = calc_range_start_addr(start_after);
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+        ReadonlyBucket::<bool>::new(storage, super::PREFIX_STAKER_INDEX)
+            .range(start.as_deref(), None, Order::Ascending)
+            .take(limit)
+            .map(|item| CanonicalAddr::from(item.unwrap().0.as_slice()))
+            .collect()
+    }
+
+    pub fn save_checkpoint(
+        storage: &mut dyn Storage,
+        owner: &CanonicalAddr,
+        height: u64,
+        amount: &Uint128,
+    ) -> StdResult<()> {
+        Bucket::<Uint128>::multilevel(storage, &[super::PREFIX_USER_CHECKPOINT, owner.as_slice()])
+            .save(&height.to_be_bytes(), amount)
+    }
+
+    // stakes never checkpointed at or before the height are reported as zero
+    pub fn load_amount_at(
+        storage: &dyn Storage,
+        owner: &CanonicalAddr,
+        height: u64,
+    ) -> StdResult<Uint128> {
+        ReadonlyBucket::<Uint128>::multilevel(
+            storage,
+            &[super::PREFIX_USER_CHECKPOINT, owner.as_slice()],
+        )
+        .range(
+            None,
+            Some(&height.saturating_add(1).to_be_bytes()),
+            Order::Descending,
+        )
+        .next()
+        .map(|item| item.map(|(_, amount)| amount))
+        .unwrap_or_else(|| Ok(Uint128::zero()))
     }
 }
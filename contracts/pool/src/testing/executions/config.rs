@@ -16,7 +16,6 @@ fn fail_unauthorized() {
         mock_env(),
         mock_info(TEST_STAKER_1, &[]),
         ExecuteMsg::Configure(ConfigureMsg::Config {
-            owner: None,
             share_token: None,
             reward_token: None,
             claim_time: None,
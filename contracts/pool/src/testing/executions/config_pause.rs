@@ -0,0 +1,159 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, from_binary};
+use pylon_gateway::pool_msg::{ConfigureMsg, ExecuteMsg, QueryMsg, Role};
+use pylon_gateway::pool_resp_v2::{PauseEventResponse, PauseHistoryResponse, PauseResponse};
+
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::testing::executions::{staking_claim, staking_deposit};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_STAKER_1};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    sender: &str,
+    deposit: Option<bool>,
+    withdraw: Option<bool>,
+    claim: Option<bool>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::Pause {
+            deposit,
+            withdraw,
+            claim,
+        }),
+    )
+}
+
+fn history_msg(start_after: Option<u64>, limit: Option<u32>) -> QueryMsg {
+    QueryMsg::PauseHistory { start_after, limit }
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let res = exec(&mut deps, TEST_OWNER, Some(true), None, Some(true)).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_pause"),
+            attr("deposit", "paused"),
+            attr("claim", "paused"),
+        ]
+    );
+
+    let pause: PauseResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pause {}).unwrap()).unwrap();
+    assert_eq!(
+        pause,
+        PauseResponse {
+            deposit: true,
+            withdraw: false,
+            claim: true,
+            emergency: false,
+        }
+    );
+
+    match staking_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_STAKER_1,
+        100,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "deposit"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+    match staking_claim::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_STAKER_1,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "claim"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    // unchanged flags are not logged
+    let res = exec(&mut deps, TEST_OWNER, Some(false), None, Some(true)).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "update_pause"), attr("deposit", "unpaused")]
+    );
+    staking_deposit::default(&mut deps, TEST_STAKER_1, 100);
+
+    let now = mock_env().block.time.seconds();
+    let history: PauseHistoryResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), history_msg(None, None)).unwrap()).unwrap();
+    assert_eq!(
+        history.events,
+        vec![
+            PauseEventResponse {
+                id: 0,
+                action: "deposit".to_string(),
+                paused: true,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+            PauseEventResponse {
+                id: 1,
+                action: "claim".to_string(),
+                paused: true,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+            PauseEventResponse {
+                id: 2,
+                action: "deposit".to_string(),
+                paused: false,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+        ]
+    );
+    let history: PauseHistoryResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), history_msg(Some(0), Some(1))).unwrap())
+            .unwrap();
+    assert_eq!(
+        history.events.iter().map(|x| x.id).collect::<Vec<_>>(),
+        vec![1]
+    );
+}
+
+#[test]
+fn success_guardian() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::AssignRole {
+            role: Role::Guardian,
+            address: Some(TEST_STAKER_1.to_string()),
+        }),
+    )
+    .unwrap();
+
+    exec(&mut deps, TEST_STAKER_1, None, Some(true), None).unwrap();
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(&mut deps, TEST_STAKER_1, Some(true), None, None) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
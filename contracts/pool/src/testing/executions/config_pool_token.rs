@@ -0,0 +1,203 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, from_binary, to_binary, Api, ContractResult, Reply, SubMsgExecutionResponse, Uint128,
+};
+use pylon_gateway::pool_msg::{ConfigureMsg, ExecuteMsg, QueryMsg};
+use pylon_gateway::pool_resp_v2::{PoolTokenResponse, TokenHistoryResponse};
+use pylon_gateway::pool_token_msg::{ConfigResponse, QueryMsg as TokenQueryMsg};
+
+use crate::constants::REPLACE_TOKEN_REPLY_ID;
+use crate::entrypoints::{execute, query, reply};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::config::Config;
+use crate::testing::{
+    instantiate, mock_deps, reply as default_reply, MockDeps, TEST_OWNER, TEST_STAKER_1,
+    TEST_STAKER_2, TEST_TOKEN,
+};
+
+const TEST_NEW_TOKEN: &str = "terra1qzz3tznv5ue4xh5wqgahmdpqavflhgh5asnk6k";
+
+fn add_pool_token(deps: &mut MockDeps, replace: bool) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::AddPoolToken {
+            code_id: 1234,
+            name: None,
+            symbol: None,
+            decimals: None,
+            replace,
+        }),
+    )
+}
+
+fn register_token_pool(deps: &mut MockDeps, pool: &'static str) {
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_NEW_TOKEN.to_string(),
+        Box::new(move |x| match from_binary::<TokenQueryMsg>(x).unwrap() {
+            TokenQueryMsg::Config {} => to_binary(&ConfigResponse {
+                pool: pool.to_string(),
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
+}
+
+fn attach_pool_token(deps: &mut MockDeps) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::AttachPoolToken {
+            address: TEST_NEW_TOKEN.to_string(),
+        }),
+    )
+}
+
+fn transfer_internal(deps: &mut MockDeps, token: &str) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(token, &[]),
+        ExecuteMsg::TransferInternal {
+            owner: TEST_STAKER_1.to_string(),
+            recipient: TEST_STAKER_2.to_string(),
+            amount: Uint128::zero(),
+        },
+    )
+}
+
+#[test]
+fn success_replace() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    default_reply::default(&mut deps);
+
+    let res = add_pool_token(&mut deps, true).unwrap();
+    assert_eq!(res.messages[0].id, REPLACE_TOKEN_REPLY_ID);
+
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: REPLACE_TOKEN_REPLY_ID,
+            result: ContractResult::Ok(SubMsgExecutionResponse {
+                events: vec![],
+                data: Some(default_reply::encode_instantiate_response(TEST_NEW_TOKEN)),
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "register_token"),
+            attr("token", TEST_NEW_TOKEN),
+            attr("replaced", TEST_TOKEN),
+        ]
+    );
+
+    let api = deps.api;
+    assert_eq!(
+        Config::load(deps.as_ref().storage).unwrap().token,
+        api.addr_validate(TEST_NEW_TOKEN).unwrap()
+    );
+
+    // the replaced token is no longer honoured
+    match transfer_internal(&mut deps, TEST_TOKEN) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+    transfer_internal(&mut deps, TEST_NEW_TOKEN).unwrap();
+
+    let now = mock_env().block.time.seconds();
+    let history: TokenHistoryResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::TokenHistory {}).unwrap()).unwrap();
+    assert_eq!(
+        history,
+        TokenHistoryResponse {
+            tokens: vec![
+                PoolTokenResponse {
+                    address: TEST_TOKEN.to_string(),
+                    registered_at: now,
+                    deactivated_at: Some(now),
+                },
+                PoolTokenResponse {
+                    address: TEST_NEW_TOKEN.to_string(),
+                    registered_at: now,
+                    deactivated_at: None,
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn success_attach() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    default_reply::default(&mut deps);
+    register_token_pool(&mut deps, MOCK_CONTRACT_ADDR);
+
+    attach_pool_token(&mut deps).unwrap();
+
+    let api = deps.api;
+    assert_eq!(
+        Config::load(deps.as_ref().storage).unwrap().token,
+        api.addr_validate(TEST_NEW_TOKEN).unwrap()
+    );
+    match transfer_internal(&mut deps, TEST_TOKEN) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_attach_invalid_pool_token() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    default_reply::default(&mut deps);
+    register_token_pool(&mut deps, TEST_STAKER_2);
+
+    match attach_pool_token(&mut deps) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidPoolToken { pool }) => assert_eq!(pool, TEST_STAKER_2),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_add_without_replace() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    default_reply::default(&mut deps);
+
+    match add_pool_token(&mut deps, false) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => assert_eq!(action, "register_token"),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_STAKER_1, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::AttachPoolToken {
+            address: TEST_NEW_TOKEN.to_string(),
+        }),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,277 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Uint128};
+use pylon_gateway::pool_msg::{ConfigureMsg, ExecuteMsg, QueryMsg, Role};
+use pylon_gateway::pool_resp_v2::RolesResponse;
+
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_STAKER_1, TEST_STAKER_2};
+
+fn configure(deps: &mut MockDeps, sender: &str, msg: ConfigureMsg) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Configure(msg),
+    )
+}
+
+fn accept_owner(deps: &mut MockDeps, sender: &str) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::AcceptOwner {},
+    )
+}
+
+fn query_roles(deps: &MockDeps) -> RolesResponse {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Roles {}).unwrap()).unwrap()
+}
+
+fn add_reward() -> ConfigureMsg {
+    ConfigureMsg::AddReward {
+        amount: Uint128::from(100u64),
+    }
+}
+
+fn config() -> ConfigureMsg {
+    ConfigureMsg::Config {
+        share_token: None,
+        reward_token: None,
+        claim_time: None,
+        deposit_time: None,
+        withdraw_time: None,
+        deposit_cap_strategy: None,
+    }
+}
+
+#[test]
+fn success_transfer_ownership() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let res = configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_STAKER_1.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "propose_owner"),
+            attr("owner", TEST_STAKER_1)
+        ]
+    );
+    assert_eq!(
+        query_roles(&deps).pending_owner,
+        Some(TEST_STAKER_1.to_string())
+    );
+
+    // ownership stays until accepted
+    configure(&mut deps, TEST_OWNER, config()).unwrap();
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::RewardManager,
+            address: Some(TEST_STAKER_2.to_string()),
+        },
+    )
+    .unwrap();
+
+    let res = accept_owner(&mut deps, TEST_STAKER_1).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "accept_owner"),
+            attr("owner", TEST_STAKER_1),
+            attr("previous", TEST_OWNER),
+        ]
+    );
+    assert_eq!(
+        query_roles(&deps),
+        RolesResponse {
+            owner: TEST_STAKER_1.to_string(),
+            pending_owner: None,
+            config_admin: None,
+            reward_manager: None,
+            guardian: None,
+        }
+    );
+
+    configure(&mut deps, TEST_STAKER_1, config()).unwrap();
+    match configure(&mut deps, TEST_OWNER, config()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn success_cancel_owner_proposal() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_STAKER_1.to_string(),
+        },
+    )
+    .unwrap();
+    configure(&mut deps, TEST_OWNER, ConfigureMsg::CancelOwnerProposal {}).unwrap();
+    assert_eq!(query_roles(&deps).pending_owner, None);
+
+    match accept_owner(&mut deps, TEST_STAKER_1) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "accept_owner".to_string(),
+                "<not assigned>".to_string(),
+                TEST_STAKER_1.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn success_assign_role() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let res = configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::RewardManager,
+            address: Some(TEST_STAKER_1.to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "assign_role"),
+            attr("role", "reward_manager"),
+            attr("address", TEST_STAKER_1),
+        ]
+    );
+    assert_eq!(
+        query_roles(&deps).reward_manager,
+        Some(TEST_STAKER_1.to_string())
+    );
+
+    // reward manager is limited to reward adjustments
+    configure(&mut deps, TEST_STAKER_1, add_reward()).unwrap();
+    match configure(&mut deps, TEST_STAKER_1, config()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::RewardManager,
+            address: None,
+        },
+    )
+    .unwrap();
+    match configure(&mut deps, TEST_STAKER_1, add_reward()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_accept_owner_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_STAKER_1.to_string(),
+        },
+    )
+    .unwrap();
+
+    match accept_owner(&mut deps, TEST_STAKER_2) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "accept_owner".to_string(),
+                TEST_STAKER_1.to_string(),
+                TEST_STAKER_2.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::ConfigAdmin,
+            address: Some(TEST_STAKER_1.to_string()),
+        },
+    )
+    .unwrap();
+    configure(&mut deps, TEST_STAKER_1, config()).unwrap();
+
+    // role holders cannot route funds, manage ownership or roles
+    for msg in [
+        ConfigureMsg::Config {
+            share_token: Some(TEST_STAKER_1.to_string()),
+            reward_token: None,
+            claim_time: None,
+            deposit_time: None,
+            withdraw_time: None,
+            deposit_cap_strategy: None,
+        },
+        ConfigureMsg::AttachPoolToken {
+            address: TEST_STAKER_1.to_string(),
+        },
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_STAKER_1.to_string(),
+        },
+        ConfigureMsg::CancelOwnerProposal {},
+        ConfigureMsg::AssignRole {
+            role: Role::RewardManager,
+            address: Some(TEST_STAKER_1.to_string()),
+        },
+    ] {
+        match configure(&mut deps, TEST_STAKER_1, msg) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::Unauthorized { .. }) => (),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+}
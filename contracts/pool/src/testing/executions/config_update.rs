@@ -7,7 +7,7 @@ use crate::executions::ExecuteResult;
 use crate::states::config::Config;
 use crate::testing::{
     instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_REWARD_TOKEN, TEST_SHARE_TOKEN,
-    TEST_STAKER_1, TEST_STAKER_2,
+    TEST_STAKER_2,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -15,7 +15,6 @@ pub fn exec(
     deps: &mut MockDeps,
     env: Env,
     info: MessageInfo,
-    owner: Option<&str>,
     share_token: Option<&str>,
     reward_token: Option<&str>,
     claim_time: Option<Vec<TimeRange>>,
@@ -27,7 +26,6 @@ pub fn exec(
         deps.as_mut(),
         env,
         info,
-        owner.map(|x| x.to_string()),
         share_token.map(|x| x.to_string()),
         reward_token.map(|x| x.to_string()),
         claim_time,
@@ -46,7 +44,6 @@ fn success() {
         &mut deps,
         mock_env(),
         mock_info(TEST_OWNER, &[]),
-        Some(TEST_STAKER_1),
         Some(TEST_REWARD_TOKEN),
         Some(TEST_SHARE_TOKEN),
         Some(vec![TimeRange::from((1, 2, false))]),
@@ -61,7 +58,7 @@ fn success() {
     assert_eq!(
         Config::load(deps.as_ref().storage).unwrap(),
         Config {
-            owner: deps.api.addr_validate(TEST_STAKER_1).unwrap(),
+            owner: deps.api.addr_validate(TEST_OWNER).unwrap(),
             token: Addr::unchecked("".to_string()),
             share_token: deps.api.addr_validate(TEST_REWARD_TOKEN).unwrap(),
             deposit_time: vec![TimeRange::from((3, 4, false))],
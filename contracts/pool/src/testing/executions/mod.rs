@@ -1,8 +1,13 @@
 pub mod config;
 pub mod config_adjust_reward;
+pub mod config_pause;
+pub mod config_pool_token;
+pub mod config_role;
 pub mod config_update;
+pub mod staking_burn;
 pub mod staking_claim;
 pub mod staking_deposit;
+pub mod staking_emergency_withdraw;
 pub mod staking_transfer;
 pub mod staking_update;
 pub mod staking_withdraw;
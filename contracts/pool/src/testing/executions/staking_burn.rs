@@ -0,0 +1,89 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{attr, to_binary, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg};
+use pylon_gateway::pool_msg::ExecuteMsg;
+
+use crate::error::ContractError;
+use crate::executions::staking::burn;
+use crate::executions::ExecuteResult;
+use crate::testing::{instantiate, mock_deps, reply, MockDeps, TEST_STAKER_1, TEST_TOKEN};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    owner: &str,
+    amount: u128,
+) -> ExecuteResult {
+    burn(
+        deps.as_mut(),
+        env,
+        info,
+        owner.to_string(),
+        Uint128::from(amount),
+    )
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    reply::default(&mut deps);
+
+    let res = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_TOKEN, &[]),
+        TEST_STAKER_1,
+        1000u128,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+                msg: to_binary(&ExecuteMsg::Update {
+                    target: Some(TEST_STAKER_1.to_string())
+                })
+                .unwrap(),
+                funds: vec![]
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_CONTRACT_ADDR.to_string(),
+                msg: to_binary(&ExecuteMsg::WithdrawInternal {
+                    sender: TEST_STAKER_1.to_string(),
+                    amount: Uint128::from(1000u128)
+                })
+                .unwrap(),
+                funds: vec![]
+            }))
+        ]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "burn_internal"),
+            attr("owner", TEST_STAKER_1),
+            attr("amount", "1000"),
+        ]
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    reply::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_STAKER_1, &[]),
+        TEST_STAKER_1,
+        1000u128,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
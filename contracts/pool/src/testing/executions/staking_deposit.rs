@@ -20,6 +20,7 @@ pub fn exec(
         env,
         info,
         sender.to_string(),
+        None,
         Uint128::from(amount),
     )
 }
@@ -69,6 +70,44 @@ fn success() {
     )
 }
 
+#[test]
+fn success_with_recipient() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const DEPOSIT_AMOUNT: u128 = 1000;
+
+    let res = deposit(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_CONTRACT_ADDR, &[]),
+        TEST_OWNER.to_string(),
+        Some(TEST_STAKER_1.to_string()),
+        Uint128::from(DEPOSIT_AMOUNT),
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "deposit"),
+            attr("sender", TEST_OWNER),
+            attr("deposit_amount", DEPOSIT_AMOUNT.to_string()),
+            attr("recipient", TEST_STAKER_1),
+        ]
+    );
+
+    let owner = deps.api.addr_canonicalize(TEST_OWNER).unwrap();
+    let recipient = deps.api.addr_canonicalize(TEST_STAKER_1).unwrap();
+    assert_eq!(
+        User::load(deps.as_ref().storage, &owner).amount,
+        Uint128::zero()
+    );
+    assert_eq!(
+        User::load(deps.as_ref().storage, &recipient).amount,
+        Uint128::from(DEPOSIT_AMOUNT)
+    );
+}
+
 #[test]
 fn fail_unauthorized() {
     let mut deps = mock_deps();
@@ -0,0 +1,152 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, to_binary, Api, CosmosMsg, Decimal, Env, MessageInfo, SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use pylon_gateway::pool_msg::{ConfigureMsg, ExecuteMsg};
+
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::reward::Reward;
+use crate::states::user::User;
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_SHARE_TOKEN, TEST_STAKER_1,
+};
+
+const STAKE_AMOUNT: u128 = 1000;
+const REWARD_AMOUNT: u128 = 50;
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::EmergencyWithdraw {})
+}
+
+fn init_with_stake(deps: &mut MockDeps) {
+    instantiate::default(deps);
+
+    let mut reward = Reward::load(deps.as_ref().storage).unwrap();
+    reward.total_deposit = Uint128::from(STAKE_AMOUNT * 2);
+    Reward::save(deps.as_mut().storage, &reward).unwrap();
+
+    let user_addr = deps.api.addr_canonicalize(TEST_STAKER_1).unwrap();
+    User::save(
+        deps.as_mut().storage,
+        &user_addr,
+        &User {
+            amount: Uint128::from(STAKE_AMOUNT),
+            reward: Uint128::from(REWARD_AMOUNT),
+            reward_per_token_paid: Decimal::zero(),
+        },
+    )
+    .unwrap();
+}
+
+fn set_emergency(deps: &mut MockDeps, emergency: bool) {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::Emergency { emergency }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    init_with_stake(&mut deps);
+
+    let default_msg = instantiate::default_msg();
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.reward_distribution_time.finish);
+
+    let res = exec(&mut deps, env, mock_info(TEST_STAKER_1, &[])).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_SHARE_TOKEN.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: TEST_STAKER_1.to_string(),
+                amount: Uint128::from(STAKE_AMOUNT)
+            })
+            .unwrap(),
+            funds: vec![]
+        }))]
+    );
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "emergency_withdraw"),
+            attr("sender", TEST_STAKER_1),
+            attr("withdraw_amount", STAKE_AMOUNT.to_string()),
+            attr("forfeited_reward", REWARD_AMOUNT.to_string()),
+        ]
+    );
+
+    let user_addr = deps.api.addr_canonicalize(TEST_STAKER_1).unwrap();
+    let user = User::load(deps.as_ref().storage, &user_addr);
+    assert_eq!(user.amount, Uint128::zero());
+    assert_eq!(user.reward, Uint128::zero());
+    assert_eq!(
+        Reward::load(deps.as_ref().storage).unwrap().total_deposit,
+        Uint128::from(STAKE_AMOUNT)
+    );
+}
+
+#[test]
+fn success_ignore_withdraw_time_in_emergency() {
+    let mut deps = mock_deps();
+    init_with_stake(&mut deps);
+    set_emergency(&mut deps, true);
+
+    // withdraw is not allowed during the default distribution period
+    exec(&mut deps, mock_env(), mock_info(TEST_STAKER_1, &[])).unwrap();
+    assert_eq!(
+        Reward::load(deps.as_ref().storage).unwrap().total_deposit,
+        Uint128::from(STAKE_AMOUNT)
+    );
+}
+
+#[test]
+fn fail_invalid_withdraw_time() {
+    let mut deps = mock_deps();
+    init_with_stake(&mut deps);
+    set_emergency(&mut deps, true);
+    set_emergency(&mut deps, false);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_STAKER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidWithdrawTime {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_nothing_to_withdraw() {
+    let mut deps = mock_deps();
+    init_with_stake(&mut deps);
+    set_emergency(&mut deps, true);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NothingToWithdraw {}) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized_emergency() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_STAKER_1, &[]),
+        ExecuteMsg::Configure(ConfigureMsg::Emergency { emergency: true }),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -90,3 +90,23 @@ fn fail_transfer_amount_exceeded() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn fail_not_allow_self_transfer() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    reply::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_TOKEN, &[]),
+        TEST_STAKER_1,
+        TEST_STAKER_1,
+        100u128,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowSelfTransfer {}) => {}
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -50,6 +50,9 @@ pub fn default_msg() -> InstantiateMsg {
 
     InstantiateMsg {
         pool_token_code_id: Some(1234),
+        pool_token_name: None,
+        pool_token_symbol: None,
+        pool_token_decimals: None,
         share_token: TEST_SHARE_TOKEN.to_string(),
         deposit_time: vec![TimeRange::from((
             default_blocktime,
@@ -89,7 +92,10 @@ fn success() {
                 admin: Some(info.sender.to_string()),
                 code_id: 1234,
                 msg: to_binary(&PoolInitMsg {
-                    pool: env.contract.address.to_string()
+                    pool: env.contract.address.to_string(),
+                    name: None,
+                    symbol: None,
+                    decimals: None,
                 })
                 .unwrap(),
                 funds: vec![],
@@ -1 +1,2 @@
 mod legacy;
+mod staker_index;
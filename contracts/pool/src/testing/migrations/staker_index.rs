@@ -0,0 +1,135 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{attr, Api, CanonicalAddr, Env, Uint128};
+use cosmwasm_storage::Bucket;
+use cw2::{get_contract_version, set_contract_version};
+use pylon_gateway::pool_msg::MigrateMsg;
+
+use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION, MIGRATION_BATCH_LIMIT};
+use crate::entrypoints;
+use crate::error::ContractError;
+use crate::migrations::staker_index::migrate;
+use crate::migrations::MigrateResult;
+use crate::states::reward::Reward;
+use crate::states::user::User;
+use crate::states::PREFIX_USER;
+use crate::testing::{mock_deps, MockDeps, TEST_OWNER, TEST_STAKER_1, TEST_STAKER_2};
+
+pub fn exec(deps: &mut MockDeps, env: Env) -> MigrateResult {
+    migrate(deps.as_mut(), env)
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    super::legacy::setup_legacy_states(&mut deps, mock_env());
+    super::legacy::exec(&mut deps, mock_env()).unwrap();
+
+    // stakes written before the index existed
+    let api = deps.api;
+    Bucket::<User>::new(deps.as_mut().storage, PREFIX_USER)
+        .save(
+            api.addr_canonicalize(TEST_OWNER).unwrap().as_slice(),
+            &User::default(),
+        )
+        .unwrap();
+    assert!(User::load_staker_range(deps.as_ref().storage, None, None).is_empty());
+
+    let res = exec(&mut deps, mock_env()).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("indexed_stakers", "3"), attr("completed", "true")]
+    );
+
+    let mut expected = vec![
+        api.addr_canonicalize(TEST_STAKER_1).unwrap(),
+        api.addr_canonicalize(TEST_STAKER_2).unwrap(),
+    ];
+    expected.sort_by(|a, b| a.as_slice().cmp(b.as_slice()));
+    assert_eq!(
+        User::load_staker_range(deps.as_ref().storage, None, None),
+        expected
+    );
+
+    // stakes before the migration are readable from its height
+    let height = mock_env().block.height;
+    assert_eq!(
+        User::load_amount_at(deps.as_ref().storage, &expected[0], height).unwrap()
+            + User::load_amount_at(deps.as_ref().storage, &expected[1], height).unwrap(),
+        Uint128::from(30000u128)
+    );
+    assert_eq!(
+        Reward::load_total_deposit_at(deps.as_ref().storage, height).unwrap(),
+        Uint128::from(30000u128)
+    );
+    assert_eq!(
+        Reward::load_total_deposit_at(deps.as_ref().storage, height - 1).unwrap(),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn success_resume_from_cursor() {
+    let mut deps = mock_deps();
+    super::legacy::setup_legacy_states(&mut deps, mock_env());
+    super::legacy::exec(&mut deps, mock_env()).unwrap();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.1").unwrap();
+
+    // more stakes than a single batch
+    for i in 0..MIGRATION_BATCH_LIMIT {
+        Bucket::<User>::new(deps.as_mut().storage, PREFIX_USER)
+            .save(
+                &[i as u8; 20],
+                &User {
+                    amount: Uint128::from(1u128),
+                    ..User::default()
+                },
+            )
+            .unwrap();
+    }
+
+    let mut env = mock_env();
+    let res = entrypoints::migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("indexed_stakers", MIGRATION_BATCH_LIMIT.to_string()),
+            attr("completed", "false")
+        ]
+    );
+    assert_eq!(
+        get_contract_version(deps.as_ref().storage).unwrap().version,
+        "0.1.1"
+    );
+
+    env.block.height += 1;
+    let res = entrypoints::migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("indexed_stakers", "2"), attr("completed", "true")]
+    );
+    assert_eq!(
+        get_contract_version(deps.as_ref().storage).unwrap().version,
+        CONTRACT_VERSION
+    );
+
+    // every stake is checkpointed once
+    let api = deps.api;
+    for (owner, amount) in [
+        (api.addr_canonicalize(TEST_STAKER_1).unwrap(), 10000u128),
+        (api.addr_canonicalize(TEST_STAKER_2).unwrap(), 20000u128),
+        (CanonicalAddr::from(vec![0u8; 20]), 1u128),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            User::load_amount_at(deps.as_ref().storage, owner, env.block.height).unwrap(),
+            Uint128::from(*amount)
+        );
+    }
+
+    match entrypoints::migrate(deps.as_mut(), env, MigrateMsg {}) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidContractVersionForMigration {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
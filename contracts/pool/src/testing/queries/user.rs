@@ -1,10 +1,13 @@
-use crate::queries::user::{query_staker, query_stakers};
+use crate::queries::reward::query_total_deposit_at;
+use crate::queries::user::{query_accounts, query_staker, query_staker_at, query_stakers};
 use crate::states::reward::Reward;
 use crate::states::user::User;
-use crate::testing::{instantiate, mock_deps, TEST_STAKER_1, TEST_STAKER_2};
-use cosmwasm_std::testing::mock_env;
-use cosmwasm_std::{from_binary, Api, Decimal, Uint128};
+use crate::testing::executions::{staking_deposit, staking_withdraw};
+use crate::testing::{instantiate, mock_deps, TEST_OWNER, TEST_STAKER_1, TEST_STAKER_2};
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{from_binary, Api, Decimal, Timestamp, Uint128};
 use pylon_gateway::pool_resp::{StakerResponse, StakersResponse};
+use pylon_gateway::pool_resp_v2::{AccountsResponse, StakerAtResponse, TotalDepositAtResponse};
 
 #[test]
 fn success() {
@@ -66,3 +69,115 @@ fn success() {
         }
     )
 }
+
+#[test]
+fn success_at() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let default_msg = instantiate::default_msg();
+    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
+
+    let mut env = mock_env();
+    env.block.height = 100;
+    staking_deposit::exec(&mut deps, env.clone(), info.clone(), TEST_STAKER_1, 1000).unwrap();
+    env.block.height = 150;
+    staking_deposit::exec(&mut deps, env.clone(), info.clone(), TEST_STAKER_2, 500).unwrap();
+    env.block.height = 200;
+    env.block.time = Timestamp::from_seconds(default_msg.reward_distribution_time.finish);
+    staking_withdraw::exec(&mut deps, env, info, TEST_STAKER_1, 400).unwrap();
+
+    for (height, staked, total_deposit) in [
+        (99u64, 0u128, 0u128),
+        (100, 1000, 1000),
+        (150, 1000, 1500),
+        (199, 1000, 1500),
+        (200, 600, 1100),
+        (1000, 600, 1100),
+    ] {
+        let response =
+            query_staker_at(deps.as_ref(), mock_env(), TEST_STAKER_1.to_string(), height).unwrap();
+        assert_eq!(
+            from_binary::<StakerAtResponse>(&response).unwrap(),
+            StakerAtResponse {
+                address: TEST_STAKER_1.to_string(),
+                height,
+                staked: Uint128::from(staked),
+            }
+        );
+
+        let response = query_total_deposit_at(deps.as_ref(), mock_env(), height).unwrap();
+        assert_eq!(
+            from_binary::<TotalDepositAtResponse>(&response).unwrap(),
+            TotalDepositAtResponse {
+                height,
+                total_deposit: Uint128::from(total_deposit),
+            }
+        );
+    }
+}
+
+#[test]
+fn success_accounts() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    for (address, amount) in [
+        (TEST_STAKER_1, 1000u128),
+        (TEST_STAKER_2, 2000),
+        (TEST_OWNER, 0),
+    ] {
+        let owner = deps.api.addr_canonicalize(address).unwrap();
+        let mut user = User::load(deps.as_ref().storage, &owner);
+        user.amount = Uint128::from(amount);
+        User::save(deps.as_mut().storage, &owner, &user).unwrap();
+    }
+
+    let mut expected = vec![TEST_STAKER_1, TEST_STAKER_2]
+        .into_iter()
+        .map(|x| deps.api.addr_canonicalize(x).unwrap())
+        .collect::<Vec<_>>();
+    expected.sort_by(|a, b| a.as_slice().cmp(b.as_slice()));
+    let expected = expected
+        .iter()
+        .map(|x| deps.api.addr_humanize(x).unwrap().to_string())
+        .collect::<Vec<_>>();
+
+    let response = query_accounts(deps.as_ref(), mock_env(), None, None).unwrap();
+    assert_eq!(
+        from_binary::<AccountsResponse>(&response).unwrap(),
+        AccountsResponse {
+            accounts: expected.clone()
+        }
+    );
+
+    let response = query_accounts(deps.as_ref(), mock_env(), None, Some(1)).unwrap();
+    assert_eq!(
+        from_binary::<AccountsResponse>(&response).unwrap(),
+        AccountsResponse {
+            accounts: vec![expected[0].clone()]
+        }
+    );
+
+    let response =
+        query_accounts(deps.as_ref(), mock_env(), Some(expected[0].clone()), None).unwrap();
+    assert_eq!(
+        from_binary::<AccountsResponse>(&response).unwrap(),
+        AccountsResponse {
+            accounts: vec![expected[1].clone()]
+        }
+    );
+
+    // withdrawing everything drops the account from the enumeration
+    let owner = deps.api.addr_canonicalize(expected[0].as_str()).unwrap();
+    let mut user = User::load(deps.as_ref().storage, &owner);
+    user.amount = Uint128::zero();
+    User::save(deps.as_mut().storage, &owner, &user).unwrap();
+
+    let response = query_accounts(deps.as_ref(), mock_env(), None, None).unwrap();
+    assert_eq!(
+        from_binary::<AccountsResponse>(&response).unwrap(),
+        AccountsResponse {
+            accounts: vec![expected[1].clone()]
+        }
+    );
+}
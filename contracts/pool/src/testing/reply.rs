@@ -2,15 +2,23 @@ use cosmwasm_std::testing::mock_env;
 use cosmwasm_std::{
     attr, Api, Binary, ContractResult, Env, Reply, Response, SubMsgExecutionResponse,
 };
-use protobuf::Message;
+use pylon_gateway::reply::ParseReplyError;
 
-use crate::constants::INSTANTIATE_REPLY_ID;
+use crate::constants::{INSTANTIATE_REPLY_ID, REPLACE_TOKEN_REPLY_ID};
 use crate::entrypoints::reply;
+use crate::error::ContractError;
 use crate::executions::ExecuteResult;
-use crate::response::MsgInstantiateContractResponse;
 use crate::states::config::Config;
 use crate::testing::{instantiate, mock_deps, MockDeps, TEST_TOKEN};
 
+// MsgInstantiateContractResponse with the contract address only
+pub fn encode_instantiate_response(contract_address: &str) -> Binary {
+    let mut encoded = vec![0x0a, contract_address.len() as u8];
+    encoded.extend_from_slice(contract_address.as_bytes());
+
+    Binary::from(encoded)
+}
+
 pub fn exec(deps: &mut MockDeps, env: Env, msg: Reply) -> ExecuteResult {
     reply(deps.as_mut(), env, msg)
 }
@@ -23,15 +31,7 @@ pub fn default(deps: &mut MockDeps) -> Response {
             id: INSTANTIATE_REPLY_ID,
             result: ContractResult::Ok(SubMsgExecutionResponse {
                 events: vec![],
-                data: Some(Binary::from(
-                    Message::write_to_bytes(&MsgInstantiateContractResponse {
-                        contract_address: TEST_TOKEN.to_string(),
-                        data: vec![],
-                        unknown_fields: Default::default(),
-                        cached_size: Default::default(),
-                    })
-                    .unwrap(),
-                )),
+                data: Some(encode_instantiate_response(TEST_TOKEN)),
             }),
         },
     )
@@ -51,3 +51,68 @@ fn success() {
         api.addr_validate(TEST_TOKEN).unwrap()
     )
 }
+
+#[test]
+fn fail_submsg_failure() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        Reply {
+            id: INSTANTIATE_REPLY_ID,
+            result: ContractResult::Err("out of gas".to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::ParseReply(ParseReplyError::SubMsgFailure(reason))) => {
+            assert_eq!(reason, "out of gas")
+        }
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn fail_malformed_data() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    for data in [None, Some(Binary::from(vec![0x0a, 0xff]))] {
+        match exec(
+            &mut deps,
+            mock_env(),
+            Reply {
+                id: INSTANTIATE_REPLY_ID,
+                result: ContractResult::Ok(SubMsgExecutionResponse {
+                    events: vec![],
+                    data,
+                }),
+            },
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::ParseReply(ParseReplyError::NoData {}))
+            | Err(ContractError::ParseReply(ParseReplyError::ParseFailure(_))) => (),
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn fail_invalid_reply_id() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        Reply {
+            id: REPLACE_TOKEN_REPLY_ID + 1,
+            result: ContractResult::Err("".to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidReplyId { .. }) => (),
+        Err(e) => panic!("Unexpected error: {:?}", e),
+    }
+}
@@ -0,0 +1,6 @@
+pub const CONTRACT_NAME: &str = "crates.io:pylon-gateway-swap-token";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// same bounds as the swap users query
+pub const MAX_QUERY_LIMIT: u32 = 30;
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
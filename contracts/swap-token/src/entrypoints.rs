@@ -0,0 +1,198 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw20::{
+    AllAccountsResponse, BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Denom,
+    DownloadLogoResponse, MarketingInfoResponse, MinterResponse, TokenInfoResponse,
+};
+use cw20_base::allowances::{
+    execute_decrease_allowance, execute_increase_allowance, query_allowance,
+};
+use cw20_base::enumerable::query_all_allowances;
+use cw20_base::state::{TokenInfo, TOKEN_INFO};
+use cw20_base::ContractError;
+use pylon_gateway::swap_token_msg::{InstantiateMsg, MigrateMsg};
+use pylon_utils::common::OrderBy;
+
+use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+use crate::executions;
+use crate::querier::Querier;
+use crate::states::Config;
+
+#[allow(dead_code)]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let querier = Querier::new(&deps.querier);
+
+    let swap_addr = deps.api.addr_validate(msg.swap.as_str())?;
+    let swap_config = querier.load_swap_config(&swap_addr)?;
+
+    let output_token = match swap_config.output_token {
+        Denom::Cw20(output_token) => output_token,
+        Denom::Native(_) => {
+            return Err(StdError::generic_err("native as output_token is not supported").into())
+        }
+    };
+    let output_token_info = querier.load_token_info(&output_token)?;
+
+    TOKEN_INFO.save(
+        deps.storage,
+        &TokenInfo {
+            name: format!("Pylon Gateway {} Swap Receipt", output_token_info.symbol),
+            symbol: format!("r{}", output_token_info.symbol),
+            decimals: output_token_info.decimals,
+            total_supply: Uint128::zero(),
+            mint: None,
+        },
+    )?;
+
+    Config::save(deps.storage, &Config { swap: swap_addr })?;
+
+    Ok(Response::default())
+}
+
+#[allow(dead_code)]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        Cw20ExecuteMsg::Transfer { recipient, amount } => {
+            executions::execute_transfer(deps, env, info, recipient, amount)
+        }
+        Cw20ExecuteMsg::TransferFrom {
+            owner,
+            recipient,
+            amount,
+        } => executions::execute_transfer_from(deps, env, info, owner, recipient, amount),
+        Cw20ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => executions::execute_send(deps, env, info, contract, amount, msg),
+        Cw20ExecuteMsg::SendFrom {
+            owner,
+            contract,
+            amount,
+            msg,
+        } => executions::execute_send_from(deps, env, info, owner, contract, amount, msg),
+        Cw20ExecuteMsg::IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => execute_increase_allowance(deps, env, info, spender, amount, expires),
+        Cw20ExecuteMsg::DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        // not used
+        Cw20ExecuteMsg::Mint { .. } => Err(ContractError::Unauthorized {}),
+        Cw20ExecuteMsg::Burn { .. } => Err(ContractError::Unauthorized {}),
+        Cw20ExecuteMsg::BurnFrom { .. } => Err(ContractError::Unauthorized {}),
+        Cw20ExecuteMsg::UpdateMarketing { .. } => Err(ContractError::Unauthorized {}),
+        Cw20ExecuteMsg::UploadLogo(_) => Err(ContractError::Unauthorized {}),
+    }
+}
+
+#[allow(dead_code)]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
+    let querier = Querier::new(&deps.querier);
+
+    match msg {
+        Cw20QueryMsg::Balance { address } => {
+            let config = Config::load(deps.storage)?;
+            let user =
+                querier.load_swap_user(&config.swap, &deps.api.addr_validate(address.as_str())?)?;
+
+            // unclaimed = claimable + locked
+            to_binary(&BalanceResponse {
+                balance: user.reward_total + user.reward_remaining,
+            })
+        }
+        Cw20QueryMsg::TokenInfo {} => {
+            let config = Config::load(deps.storage)?;
+            let swap_state = querier.load_swap_state(&config.swap)?;
+
+            let mut token_info = TOKEN_INFO.load(deps.storage)?;
+            token_info.total_supply = swap_state.total_swapped - swap_state.total_claimed;
+
+            to_binary(&TokenInfoResponse {
+                name: token_info.name,
+                symbol: token_info.symbol,
+                decimals: token_info.decimals,
+                total_supply: token_info.total_supply,
+            })
+        }
+        Cw20QueryMsg::Allowance { owner, spender } => {
+            Ok(to_binary(&query_allowance(deps, owner, spender)?)?)
+        }
+        Cw20QueryMsg::AllAllowances {
+            owner,
+            start_after,
+            limit,
+        } => Ok(to_binary(&query_all_allowances(
+            deps,
+            owner,
+            start_after,
+            limit,
+        )?)?),
+        Cw20QueryMsg::AllAccounts { start_after, limit } => {
+            let config = Config::load(deps.storage)?;
+            let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+            // accounts without balance are skipped, so keep paging until the response is filled
+            let mut start_after = start_after;
+            let mut accounts = vec![];
+            while accounts.len() < limit {
+                let swap_users = querier.load_swap_users(
+                    &config.swap,
+                    start_after.clone(),
+                    Some(MAX_QUERY_LIMIT),
+                    Some(OrderBy::Asc),
+                )?;
+                let fetched = swap_users.users.len();
+
+                for (address, user) in swap_users.users.into_iter() {
+                    if accounts.len() == limit {
+                        break;
+                    }
+                    start_after = Some(address.clone());
+                    if !(user.reward_total + user.reward_remaining).is_zero() {
+                        accounts.push(address);
+                    }
+                }
+                if fetched < MAX_QUERY_LIMIT as usize {
+                    break;
+                }
+            }
+
+            to_binary(&AllAccountsResponse { accounts })
+        }
+        // not used
+        Cw20QueryMsg::Minter {} => to_binary(&Option::<MinterResponse>::None),
+        Cw20QueryMsg::MarketingInfo { .. } => to_binary(&Option::<MarketingInfoResponse>::None),
+        Cw20QueryMsg::DownloadLogo { .. } => to_binary(&Option::<DownloadLogoResponse>::None),
+    }
+}
+
+#[allow(dead_code)]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    Ok(Response::default())
+}
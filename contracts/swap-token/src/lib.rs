@@ -0,0 +1,8 @@
+mod constants;
+mod entrypoints;
+mod executions;
+mod querier;
+mod states;
+
+#[cfg(test)]
+mod testing;
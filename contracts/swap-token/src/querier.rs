@@ -0,0 +1,73 @@
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
+use pylon_gateway::swap_msg;
+use pylon_gateway::swap_resp_v2;
+use pylon_utils::common::OrderBy;
+
+pub struct Querier<'a> {
+    querier: &'a QuerierWrapper<'a>,
+}
+
+impl Querier<'_> {
+    pub fn new<'a>(querier: &'a QuerierWrapper<'a>) -> Querier<'a> {
+        Querier { querier }
+    }
+
+    pub fn load_swap_config(&self, swap: &Addr) -> StdResult<swap_resp_v2::ConfigResponse> {
+        let swap_config: swap_resp_v2::ConfigResponse = self
+            .querier
+            .query_wasm_smart(swap, &swap_msg::QueryMsg::ConfigV2 {})?;
+
+        Ok(swap_config)
+    }
+
+    pub fn load_swap_state(&self, swap: &Addr) -> StdResult<swap_resp_v2::StateResponse> {
+        let swap_state: swap_resp_v2::StateResponse = self
+            .querier
+            .query_wasm_smart(swap, &swap_msg::QueryMsg::State {})?;
+
+        Ok(swap_state)
+    }
+
+    pub fn load_swap_user(
+        &self,
+        swap: &Addr,
+        owner: &Addr,
+    ) -> StdResult<swap_resp_v2::UserResponse> {
+        let swap_user: swap_resp_v2::UserResponse = self.querier.query_wasm_smart(
+            swap,
+            &swap_msg::QueryMsg::User {
+                address: owner.to_string(),
+            },
+        )?;
+
+        Ok(swap_user)
+    }
+
+    pub fn load_swap_users(
+        &self,
+        swap: &Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order: Option<OrderBy>,
+    ) -> StdResult<swap_resp_v2::UsersResponse> {
+        let swap_users: swap_resp_v2::UsersResponse = self.querier.query_wasm_smart(
+            swap,
+            &swap_msg::QueryMsg::Users {
+                start_after,
+                limit,
+                order,
+            },
+        )?;
+
+        Ok(swap_users)
+    }
+
+    pub fn load_token_info(&self, token: &Addr) -> StdResult<TokenInfoResponse> {
+        let token_info: TokenInfoResponse = self
+            .querier
+            .query_wasm_smart(token, &Cw20QueryMsg::TokenInfo {})?;
+
+        Ok(token_info)
+    }
+}
@@ -0,0 +1,108 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Api, Decimal, Env, MessageInfo, Response, Uint128,
+};
+use cw20::{Cw20QueryMsg, Denom, TokenInfoResponse};
+use cw20_base::state::{TokenInfo, TOKEN_INFO};
+use pylon_gateway::swap_msg::QueryMsg as SwapQueryMsg;
+use pylon_gateway::swap_resp_v2::ConfigResponse;
+use pylon_gateway::swap_token_msg::InstantiateMsg;
+
+use crate::entrypoints::instantiate;
+use crate::executions::ExecuteResult;
+use crate::states::Config;
+use crate::testing::{mock_deps, MockDeps, TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_SWAP};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ExecuteResult {
+    instantiate(deps.as_mut(), env, info, msg)
+}
+
+pub fn default(deps: &mut MockDeps) -> (Env, MessageInfo, Response) {
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_SWAP.to_string(),
+        Box::new(|x| match from_binary::<SwapQueryMsg>(x).unwrap() {
+            SwapQueryMsg::ConfigV2 {} => to_binary(&ConfigResponse {
+                owner: "".to_string(),
+                beneficiary: "".to_string(),
+                start: 0,
+                finish: 0,
+                price: Decimal::one(),
+                amount: Uint128::zero(),
+                input_token: Denom::Native("uusd".to_string()),
+                output_token: Denom::Cw20(Addr::unchecked(TEST_OUTPUT_TOKEN)),
+                deposit_cap_strategy: None,
+                distribution_strategies: vec![],
+                tier_distribution_strategies: vec![],
+                deposit_tier: None,
+                whitelist_enabled: false,
+                receipt_token: None,
+                soft_cap: None,
+                earn_by_claim: false,
+                protocol_admin: None,
+                protocol_fee_rate: Decimal::zero(),
+                protocol_fee_collector: None,
+                protocol_fee_on_claim: false,
+                liquidity_seed: None,
+                deposit_routes: vec![],
+                referral_reward: None,
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_OUTPUT_TOKEN.to_string(),
+        Box::new(|x| match from_binary::<Cw20QueryMsg>(x).unwrap() {
+            Cw20QueryMsg::TokenInfo {} => to_binary(&TokenInfoResponse {
+                name: "".to_string(),
+                symbol: "TOT".to_string(),
+                decimals: 6,
+                total_supply: Default::default(),
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    let env = mock_env();
+    let info = mock_info(TEST_OWNER, &[]);
+    let res = exec(deps, env.clone(), info.clone(), default_msg()).unwrap();
+
+    (env, info, res)
+}
+
+pub fn default_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        swap: TEST_SWAP.to_string(),
+    }
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    default(&mut deps);
+
+    let api = deps.api;
+
+    assert_eq!(
+        TOKEN_INFO.load(deps.as_ref().storage).unwrap(),
+        TokenInfo {
+            name: "Pylon Gateway TOT Swap Receipt".to_string(),
+            symbol: "rTOT".to_string(),
+            decimals: 6,
+            total_supply: Uint128::zero(),
+            mint: None
+        }
+    );
+
+    assert_eq!(
+        Config::load(deps.as_ref().storage).unwrap(),
+        Config {
+            swap: api.addr_validate(TEST_SWAP).unwrap()
+        }
+    );
+}
@@ -0,0 +1,63 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{from_binary, to_binary, Uint128};
+use cw20::{AllAccountsResponse, Cw20QueryMsg};
+use pylon_gateway::swap_msg::QueryMsg as SwapQueryMsg;
+use pylon_gateway::swap_resp_v2::{UserResponse, UsersResponse};
+use pylon_utils::common::OrderBy;
+
+use crate::entrypoints::query;
+use crate::testing::{instantiate, mock_deps, TEST_RECIPIENT, TEST_SENDER, TEST_SWAP};
+
+fn user(reward: u128) -> UserResponse {
+    UserResponse {
+        whitelisted: false,
+        tier: None,
+        swapped_in: Uint128::zero(),
+        available_cap: None,
+        reward_total: Uint128::zero(),
+        reward_remaining: Uint128::from(reward),
+    }
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    const EMPTY: &str = "empty";
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_SWAP.to_string(),
+        Box::new(|x| match from_binary::<SwapQueryMsg>(x).unwrap() {
+            SwapQueryMsg::Users {
+                start_after: None,
+                order: Some(OrderBy::Asc),
+                ..
+            } => to_binary(&UsersResponse {
+                users: vec![
+                    (EMPTY.to_string(), user(0)),
+                    (TEST_RECIPIENT.to_string(), user(100)),
+                    (TEST_SENDER.to_string(), user(200)),
+                ],
+            }),
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        Cw20QueryMsg::AllAccounts {
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let response = from_binary::<AllAccountsResponse>(&response).unwrap();
+    assert_eq!(
+        response,
+        AllAccountsResponse {
+            accounts: vec![TEST_RECIPIENT.to_string(), TEST_SENDER.to_string()]
+        }
+    );
+}
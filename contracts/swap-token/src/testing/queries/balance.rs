@@ -0,0 +1,48 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{from_binary, to_binary, Uint128};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+use pylon_gateway::swap_msg::QueryMsg as SwapQueryMsg;
+use pylon_gateway::swap_resp_v2::UserResponse;
+
+use crate::entrypoints::query;
+use crate::testing::{instantiate, mock_deps, TEST_SENDER, TEST_SWAP};
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_SWAP.to_string(),
+        Box::new(|x| match from_binary::<SwapQueryMsg>(x).unwrap() {
+            SwapQueryMsg::User { address } => match address.as_str() {
+                TEST_SENDER => to_binary(&UserResponse {
+                    whitelisted: false,
+                    tier: None,
+                    swapped_in: Uint128::from(100u128),
+                    available_cap: None,
+                    reward_total: Uint128::from(300u128),
+                    reward_remaining: Uint128::from(700u128),
+                }),
+                _ => panic!("Unexpected user address"),
+            },
+            _ => panic!("Unsupported query"),
+        }),
+    );
+
+    let response = query(
+        deps.as_ref(),
+        mock_env(),
+        Cw20QueryMsg::Balance {
+            address: TEST_SENDER.to_string(),
+        },
+    )
+    .unwrap();
+    let response = from_binary::<BalanceResponse>(&response).unwrap();
+    assert_eq!(
+        response,
+        BalanceResponse {
+            balance: Uint128::from(1000u128),
+        }
+    );
+}
@@ -0,0 +1,2 @@
+mod all_accounts;
+mod balance;
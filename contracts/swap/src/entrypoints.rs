@@ -1,12 +1,16 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, Uint128};
 use cw2::set_contract_version;
 use cw20::Denom;
-use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use pylon_gateway::pause::{self, PauseUpdate};
+use pylon_gateway::role::{self, Roles};
+use pylon_gateway::swap_msg::{
+    ConfigureMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Role,
+};
 
-use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION};
+use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION, DEPOSIT_ROUTE_REPLY_ID};
 use crate::error::ContractError;
 use crate::states::config::Config;
 use crate::states::state::State;
@@ -25,26 +29,42 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let api = deps.api;
-    Config::save(
-        deps.storage,
-        &Config {
-            owner: info.sender,
-            beneficiary: api.addr_validate(msg.beneficiary.as_str())?,
-            start: msg.start,
-            finish: msg.start + msg.period,
-            price: msg.price,
-            amount: msg.amount,
-            input_token: Denom::Native(msg.input_token),
-            output_token: Denom::Cw20(api.addr_validate(msg.output_token.as_str())?),
-            deposit_cap_strategy: msg.deposit_cap_strategy.map(CapStrategy::from),
-            distribution_strategies: msg
-                .distribution_strategies
-                .iter()
-                .map(|x| DistributionStrategy::from(x.clone()))
-                .collect(),
-            whitelist_enabled: msg.whitelist_enabled,
-        },
-    )?;
+    let config = Config {
+        owner: info.sender,
+        beneficiary: api.addr_validate(msg.beneficiary.as_str())?,
+        proceeds_beneficiaries: vec![],
+        penalty_beneficiaries: vec![],
+        start: msg.start,
+        finish: msg.start + msg.period,
+        price: msg.price,
+        amount: msg.amount,
+        input_token: Denom::Native(msg.input_token),
+        output_token: Denom::Cw20(api.addr_validate(msg.output_token.as_str())?),
+        deposit_cap_strategy: msg.deposit_cap_strategy.map(CapStrategy::from),
+        distribution_strategies: msg
+            .distribution_strategies
+            .iter()
+            .map(|x| DistributionStrategy::from(x.clone()))
+            .collect(),
+        tier_distribution_strategies: vec![],
+        deposit_tier: None,
+        whitelist_enabled: msg.whitelist_enabled,
+        receipt_token: None,
+        soft_cap: msg.soft_cap,
+        earn_by_claim: msg.earn_by_claim,
+        protocol_admin: msg
+            .protocol_admin
+            .map(|x| api.addr_validate(x.as_str()))
+            .transpose()?,
+        protocol_fee_rate: Decimal::zero(),
+        protocol_fee_collector: None,
+        protocol_fee_on_claim: false,
+        liquidity_seed: None,
+        deposit_routes: vec![],
+        referral_reward: None,
+    };
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
 
     State::save(
         deps.storage,
@@ -53,6 +73,17 @@ pub fn instantiate(
             total_claimed: Uint128::zero(),
             x_liquidity: msg.x_liquidity,
             y_liquidity: msg.y_liquidity,
+            cancelled: false,
+            total_raised: Uint128::zero(),
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero(),
         },
     )?;
 
@@ -70,12 +101,51 @@ pub fn execute(
     match msg {
         ExecuteMsg::Configure(cfg_msg) => {
             let config = Config::load(deps.storage)?;
-            if config.owner != info.sender {
+            // the owner holds every role. anything routing funds stays owner only
+            let role = match *cfg_msg {
+                ConfigureMsg::Config {
+                    beneficiary: None,
+                    input_token: None,
+                    output_token: None,
+                    receipt_token: None,
+                    ..
+                }
+                | ConfigureMsg::Snapshot { .. }
+                | ConfigureMsg::TierStrategy { .. }
+                | ConfigureMsg::DepositTier { .. } => Some(Role::ConfigAdmin),
+                ConfigureMsg::Whitelist { .. } | ConfigureMsg::Tier { .. } => {
+                    Some(Role::WhitelistManager)
+                }
+                ConfigureMsg::ReferralReward { .. } => Some(Role::RewardManager),
+                ConfigureMsg::Pause { .. } => Some(Role::Guardian),
+                ConfigureMsg::Config { .. }
+                | ConfigureMsg::State { .. }
+                | ConfigureMsg::Beneficiaries { .. }
+                | ConfigureMsg::LiquiditySeed { .. }
+                | ConfigureMsg::DepositRoute { .. }
+                | ConfigureMsg::ProposeOwner { .. }
+                | ConfigureMsg::CancelOwnerProposal { .. }
+                | ConfigureMsg::AssignRole { .. } => None,
+            };
+            let roles = Roles::<Role>::load(deps.storage)?;
+            if config.owner != info.sender && !roles.is_granted(role.as_ref(), &info.sender) {
                 return Err(ContractError::Unauthorized {
                     action: match *cfg_msg {
                         ConfigureMsg::Config { .. } => "update_config",
                         ConfigureMsg::State { .. } => "update_state",
                         ConfigureMsg::Whitelist { .. } => "whitelist",
+                        ConfigureMsg::Snapshot { .. } => "snapshot",
+                        ConfigureMsg::TierStrategy { .. } => "update_tier_strategy",
+                        ConfigureMsg::Tier { .. } => "tier",
+                        ConfigureMsg::DepositTier { .. } => "update_deposit_tier",
+                        ConfigureMsg::Beneficiaries { .. } => "update_beneficiaries",
+                        ConfigureMsg::LiquiditySeed { .. } => "update_liquidity_seed",
+                        ConfigureMsg::DepositRoute { .. } => "update_deposit_route",
+                        ConfigureMsg::ReferralReward { .. } => "update_referral_reward",
+                        ConfigureMsg::ProposeOwner { .. } => "propose_owner",
+                        ConfigureMsg::CancelOwnerProposal { .. } => "cancel_owner_proposal",
+                        ConfigureMsg::AssignRole { .. } => "assign_role",
+                        ConfigureMsg::Pause { .. } => "update_pause",
                     }
                     .to_string(),
                     expected: config.owner.to_string(),
@@ -85,24 +155,26 @@ pub fn execute(
 
             match *cfg_msg {
                 ConfigureMsg::Config {
-                    owner,
                     beneficiary,
                     input_token,
                     output_token,
                     deposit_cap_strategy,
                     distribution_strategies,
                     whitelist_enabled,
+                    receipt_token,
+                    earn_by_claim,
                 } => executions::config::update(
                     deps,
                     env,
                     info,
-                    owner,
                     beneficiary,
                     input_token,
                     output_token,
                     deposit_cap_strategy,
                     distribution_strategies,
                     whitelist_enabled,
+                    receipt_token,
+                    earn_by_claim,
                 ),
                 ConfigureMsg::State {
                     x_liquidity,
@@ -112,12 +184,114 @@ pub fn execute(
                     whitelist,
                     candidates,
                 } => executions::user::whitelist(deps, env, info, whitelist, candidates),
+                ConfigureMsg::Snapshot { stakes } => {
+                    executions::user::snapshot(deps, env, info, stakes)
+                }
+                ConfigureMsg::TierStrategy {
+                    tier,
+                    distribution_strategies,
+                } => executions::config::update_tier_strategy(
+                    deps,
+                    env,
+                    info,
+                    tier,
+                    distribution_strategies,
+                ),
+                ConfigureMsg::Tier { tier, candidates } => {
+                    executions::user::tier(deps, env, info, tier, candidates)
+                }
+                ConfigureMsg::DepositTier { tier } => {
+                    executions::config::update_deposit_tier(deps, env, info, tier)
+                }
+                ConfigureMsg::Beneficiaries { proceeds, penalty } => {
+                    executions::config::update_beneficiaries(deps, env, info, proceeds, penalty)
+                }
+                ConfigureMsg::LiquiditySeed { liquidity_seed } => {
+                    executions::config::update_liquidity_seed(deps, env, info, liquidity_seed)
+                }
+                ConfigureMsg::DepositRoute { asset, pair } => {
+                    executions::config::update_deposit_route(deps, env, info, asset, pair)
+                }
+                ConfigureMsg::ReferralReward { reward } => {
+                    executions::config::update_referral_reward(deps, env, info, reward)
+                }
+                ConfigureMsg::ProposeOwner { owner } => {
+                    Ok(role::propose_owner::<Role>(deps.storage, deps.api, owner)?)
+                }
+                ConfigureMsg::CancelOwnerProposal {} => {
+                    Ok(role::cancel_owner_proposal::<Role>(deps.storage)?)
+                }
+                ConfigureMsg::AssignRole { role, address } => {
+                    Ok(role::assign_role(deps.storage, deps.api, role, address)?)
+                }
+                ConfigureMsg::Pause {
+                    deposit,
+                    withdraw,
+                    claim,
+                    earn,
+                } => Ok(pause::update(
+                    deps.storage,
+                    &info.sender,
+                    env.block.time.seconds(),
+                    PauseUpdate {
+                        deposit,
+                        withdraw,
+                        claim,
+                        earn,
+                        ..PauseUpdate::default()
+                    },
+                )?),
             }
         }
-        ExecuteMsg::Deposit {} => executions::swap::deposit(deps, env, info),
+        ExecuteMsg::AcceptOwner {} => executions::config::accept_owner(deps, env, info),
+        ExecuteMsg::Register {} => executions::user::register(deps, env, info),
+        ExecuteMsg::Deposit {
+            referrer,
+            recipient,
+        } => executions::swap::deposit(deps, env, info, referrer, recipient),
+        ExecuteMsg::DepositRouted {
+            min_receive,
+            referrer,
+        } => executions::route::deposit(deps, env, info, min_receive, referrer),
+        ExecuteMsg::Receive(msg) => executions::route::receive(deps, env, info, msg),
         ExecuteMsg::Withdraw { amount } => executions::swap::withdraw(deps, env, info, amount),
         ExecuteMsg::Claim {} => executions::swap::claim(deps, env, info),
-        ExecuteMsg::Earn {} => executions::swap::earn(deps, env, info),
+        ExecuteMsg::ClaimReferralReward {} => executions::referral::claim(deps, env, info),
+        ExecuteMsg::ProvideLiquidity {} => executions::liquidity::provide(deps, env, info),
+        ExecuteMsg::ReleaseLiquidity { recipient, amount } => {
+            executions::liquidity::release(deps, env, info, recipient, amount)
+        }
+        ExecuteMsg::Earn { amount } => executions::swap::earn(deps, env, info, amount),
+        ExecuteMsg::Refund {} => executions::swap::refund(deps, env, info),
+        ExecuteMsg::Cancel {} => executions::swap::cancel(deps, env, info),
+        ExecuteMsg::ConfigureProtocol {
+            admin,
+            fee_rate,
+            fee_collector,
+            fee_on_claim,
+        } => executions::config::update_protocol(
+            deps,
+            env,
+            info,
+            admin,
+            fee_rate,
+            fee_collector,
+            fee_on_claim,
+        ),
+        ExecuteMsg::TransferInternal {
+            owner,
+            recipient,
+            amount,
+        } => executions::swap::transfer(deps, env, info, owner, recipient, amount),
+    }
+}
+
+#[allow(dead_code)]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        DEPOSIT_ROUTE_REPLY_ID => executions::route::reply(deps, env, msg),
+        _ => Err(ContractError::InvalidReplyId { id: msg.id }),
     }
 }
 
@@ -148,6 +322,29 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             limit,
             order,
         } => queries::user::query_users(deps, env, start_after, limit, order),
+        QueryMsg::StakeSnapshotOf { address } => {
+            queries::user::query_stake_snapshot_of(deps, env, address)
+        }
+        QueryMsg::CapPhase {} => queries::config::query_cap_phase(deps, env),
+        QueryMsg::Referrer { address } => queries::referrer::query_referrer(deps, env, address),
+        QueryMsg::TopReferrers { limit } => {
+            queries::referrer::query_top_referrers(deps, env, limit)
+        }
+        QueryMsg::Beneficiaries {} => queries::config::query_beneficiaries(deps, env),
+        QueryMsg::Roles {} => queries::config::query_roles(deps, env),
+        QueryMsg::Pause {} => queries::config::query_pause(deps, env),
+        QueryMsg::PauseHistory { start_after, limit } => {
+            queries::config::query_pause_history(deps, env, start_after, limit)
+        }
+        QueryMsg::ValidateConfig {
+            deposit_cap_strategy,
+            distribution_strategies,
+        } => queries::config::query_validate_config(
+            deps,
+            env,
+            deposit_cap_strategy,
+            distribution_strategies,
+        ),
 
         // common
         QueryMsg::CurrentPrice {} => queries::swap::query_current_price(deps),
@@ -168,6 +365,6 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
         MigrateMsg::Valkyrie {
             deposit_cap_strategy,
         } => migrations::valkyrie::migrate(deps, env, deposit_cap_strategy),
-        MigrateMsg::General {} => Ok(Response::default()),
+        MigrateMsg::General {} => migrations::general::migrate(deps, env),
     }
 }
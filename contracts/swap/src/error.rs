@@ -1,4 +1,6 @@
-use cosmwasm_std::{OverflowError, StdError, Uint128};
+use cosmwasm_std::{Decimal, OverflowError, StdError, Uint128};
+use pylon_gateway::pause::PauseError;
+use pylon_gateway::role::RoleError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -53,4 +55,152 @@ pub enum ContractError {
 
     #[error("Gateway/Swap: earn not allowed before lock period")]
     NotAllowEarnBeforeLockPeriod {},
+
+    #[error("Gateway/Swap: registration not allowed after start. (time: {start:?})")]
+    NotAllowRegisterAfterStart { start: u64 },
+
+    #[error("Gateway/Swap: registration not enabled for deposit cap strategy")]
+    RegistrationNotEnabled {},
+
+    #[error("Gateway/Swap: already registered")]
+    AlreadyRegistered {},
+
+    #[error("Gateway/Swap: tier not found (tier: {tier:?})")]
+    TierNotFound { tier: String },
+
+    #[error("Gateway/Swap: tier still in use (tier: {tier:?})")]
+    TierInUse { tier: String },
+
+    #[error("Gateway/Swap: tier change not allowed after token claim (address: {address:?})")]
+    NotAllowTierAfterClaim { address: String },
+
+    #[error("Gateway/Swap: transfer amount exceeds unclaimed amount (Available: {available:?})")]
+    TransferAmountExceeded { available: Uint128 },
+
+    #[error("Gateway/Swap: transfer not allowed between different tiers")]
+    NotAllowTransferBetweenTiers {},
+
+    #[error("Gateway/Swap: deposit for recipient not allowed between different tiers")]
+    NotAllowDepositBetweenTiers {},
+
+    #[error("Gateway/Swap: not allowed in refund mode")]
+    NotAllowInRefundMode {},
+
+    #[error("Gateway/Swap: refund not allowed before cancel or soft cap failure")]
+    NotAllowRefund {},
+
+    #[error("Gateway/Swap: cancel not allowed after lock period")]
+    NotAllowCancelAfterLockPeriod {},
+
+    #[error("Gateway/Swap: already cancelled")]
+    AlreadyCancelled {},
+
+    #[error("Gateway/Swap: earn amount exceeds earnable amount (Available: {available:?})")]
+    EarnAmountExceeded { available: Uint128 },
+
+    #[error("Gateway/Swap: invalid user cap range (min: {min:?}, max: {max:?})")]
+    InvalidUserCapRange { min: Uint128, max: Uint128 },
+
+    #[error("Gateway/Swap: invalid stake amount range (min: {min:?}, max: {max:?})")]
+    InvalidStakeAmountRange { min: Uint128, max: Uint128 },
+
+    #[error("Gateway/Swap: invalid stage range (index: {index:?})")]
+    InvalidStageRange { index: u32 },
+
+    #[error("Gateway/Swap: stages must be sorted and not overlapped (index: {index:?})")]
+    OverlappedStages { index: u32 },
+
+    #[error("Gateway/Swap: phases must be sorted by start time (index: {index:?})")]
+    UnsortedPhases { index: u32 },
+
+    #[error("Gateway/Swap: composite strategy requires at least one strategy")]
+    EmptyStrategies {},
+
+    #[error("Gateway/Swap: invalid release time (start: {start:?}, finish: {finish:?})")]
+    InvalidReleaseTime { start: u64, finish: u64 },
+
+    #[error("Gateway/Swap: invalid release period (interval: {interval:?}, count: {count:?})")]
+    InvalidReleasePeriod { interval: u64, count: u64 },
+
+    #[error("Gateway/Swap: release amount overflows (amount_per_period: {amount_per_period:?}, count: {count:?})")]
+    ReleaseAmountOverflow {
+        amount_per_period: Decimal,
+        count: u64,
+    },
+
+    #[error("Gateway/Swap: sum of release amounts must be 1 (sum: {sum:?})")]
+    InvalidReleaseAmountSum { sum: Decimal },
+
+    #[error("Gateway/Swap: beneficiary shares must be non-zero and sum to 1 (sum: {sum:?})")]
+    InvalidBeneficiaryShares { sum: Decimal },
+
+    #[error("Gateway/Swap: protocol fee rate must not exceed 1 and requires a collector (rate: {rate:?})")]
+    InvalidProtocolFee { rate: Decimal },
+
+    #[error("Gateway/Swap: liquidity seed ratio must not exceed 1 (ratio: {ratio:?})")]
+    InvalidLiquiditySeedRatio { ratio: Decimal },
+
+    #[error("Gateway/Swap: liquidity seed not configured")]
+    LiquiditySeedNotConfigured {},
+
+    #[error("Gateway/Swap: liquidity not provided")]
+    LiquidityNotProvided {},
+
+    #[error(
+        "Gateway/Swap: insufficient output token for liquidity seed (available: {available:?})"
+    )]
+    InsufficientLiquiditySeedBalance { available: Uint128 },
+
+    #[error("Gateway/Swap: liquidity already provided")]
+    LiquidityAlreadyProvided {},
+
+    #[error("Gateway/Swap: earn not allowed before liquidity provision")]
+    NotAllowEarnBeforeLiquidity {},
+
+    #[error("Gateway/Swap: referral reward rate must not exceed 1 (rate: {rate:?})")]
+    InvalidReferralRewardRate { rate: Decimal },
+
+    #[error("Gateway/Swap: self referral not allowed")]
+    NotAllowSelfReferral {},
+
+    #[error("Gateway/Swap: referral reward claim not allowed before lock period")]
+    NotAllowReferralClaimBeforeLockPeriod {},
+
+    #[error("Gateway/Swap: deposit route not found (asset: {asset:?})")]
+    DepositRouteNotFound { asset: String },
+
+    #[error("Gateway/Swap: swapped amount is below minimum (received: {received:?}, min_receive: {min_receive:?})")]
+    MinReceiveNotMet {
+        received: Uint128,
+        min_receive: Uint128,
+    },
+
+    #[error("Gateway/Swap: paused (action: {action:?})")]
+    Paused { action: String },
+}
+
+impl From<RoleError> for ContractError {
+    fn from(err: RoleError) -> Self {
+        match err {
+            RoleError::Std(err) => ContractError::Std(err),
+            RoleError::Unauthorized {
+                action,
+                expected,
+                actual,
+            } => ContractError::Unauthorized {
+                action,
+                expected,
+                actual,
+            },
+        }
+    }
+}
+
+impl From<PauseError> for ContractError {
+    fn from(err: PauseError) -> Self {
+        match err {
+            PauseError::Std(err) => ContractError::Std(err),
+            PauseError::Paused { action } => ContractError::Paused { action },
+        }
+    }
 }
@@ -1,32 +1,36 @@
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{attr, Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw20::Denom;
+use pylon_gateway::role;
+use pylon_gateway::swap_msg::Role;
 use pylon_gateway::swap_types::{
     CapStrategy as SwapCapStrategy, DistributionStrategy as SwapDistributionStrategy,
+    LiquiditySeed as SwapLiquiditySeed, ReferralReward as SwapReferralReward,
 };
 
-use crate::states::config::Config;
+use crate::error::ContractError;
+use crate::states::config::{Config, LiquiditySeed};
+use crate::states::state::State;
+use crate::states::user::User;
 use crate::types::cap_strategy::CapStrategy;
 use crate::types::distribution_strategy::DistributionStrategy;
+use crate::types::referral_reward::ReferralReward;
 
 #[allow(clippy::too_many_arguments)]
 pub fn update(
     deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
-    owner: Option<String>,
     beneficiary: Option<String>,
     input_token: Option<String>,
     output_token: Option<String>,
     deposit_cap_strategy: Option<SwapCapStrategy>,
     distribution_strategies: Option<Vec<SwapDistributionStrategy>>,
     whitelist_enabled: Option<bool>,
+    receipt_token: Option<String>,
+    earn_by_claim: Option<bool>,
 ) -> super::ExecuteResult {
     let mut config = Config::load(deps.storage)?;
 
-    if let Some(v) = owner {
-        config.owner = deps.api.addr_validate(v.as_str())?;
-    }
-
     if let Some(v) = beneficiary {
         config.beneficiary = deps.api.addr_validate(v.as_str())?;
     }
@@ -52,7 +56,226 @@ pub fn update(
         config.whitelist_enabled = v;
     }
 
+    if let Some(v) = receipt_token {
+        config.receipt_token = Some(deps.api.addr_validate(v.as_str())?);
+    }
+
+    if let Some(v) = earn_by_claim {
+        config.earn_by_claim = v;
+    }
+
+    config.validate()?;
     Config::save(deps.storage, &config)?;
 
     Ok(Response::new().add_attributes(vec![attr("action", "update_config")]))
 }
+
+pub fn update_tier_strategy(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    tier: String,
+    distribution_strategies: Option<Vec<SwapDistributionStrategy>>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+
+    // users of a removed tier would silently fall back to the global schedule
+    if distribution_strategies.is_none()
+        && (config.deposit_tier.as_ref() == Some(&tier)
+            || User::count_tier(deps.storage, &tier) != 0)
+    {
+        return Err(ContractError::TierInUse { tier });
+    }
+
+    config
+        .tier_distribution_strategies
+        .retain(|(name, _)| *name != tier);
+    if let Some(v) = distribution_strategies {
+        config.tier_distribution_strategies.push((
+            tier.clone(),
+            v.into_iter().map(DistributionStrategy::from).collect(),
+        ));
+    }
+
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_tier_strategy"),
+        attr("tier", tier),
+    ]))
+}
+
+pub fn update_deposit_tier(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    tier: Option<String>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+
+    if let Some(tier) = tier.as_ref() {
+        if !config.has_tier(tier) {
+            return Err(ContractError::TierNotFound { tier: tier.clone() });
+        }
+    }
+    config.deposit_tier = tier;
+
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_deposit_tier")]))
+}
+
+pub fn update_beneficiaries(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    proceeds: Option<Vec<(String, Decimal)>>,
+    penalty: Option<Vec<(String, Decimal)>>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+
+    if let Some(v) = proceeds {
+        config.proceeds_beneficiaries = validate_beneficiaries(deps.as_ref(), v)?;
+    }
+
+    if let Some(v) = penalty {
+        config.penalty_beneficiaries = validate_beneficiaries(deps.as_ref(), v)?;
+    }
+
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_beneficiaries")]))
+}
+
+fn validate_beneficiaries(
+    deps: Deps,
+    beneficiaries: Vec<(String, Decimal)>,
+) -> StdResult<Vec<(Addr, Decimal)>> {
+    beneficiaries
+        .into_iter()
+        .map(|(address, share)| Ok((deps.api.addr_validate(address.as_str())?, share)))
+        .collect()
+}
+
+pub fn update_protocol(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    admin: Option<String>,
+    fee_rate: Option<Decimal>,
+    fee_collector: Option<String>,
+    fee_on_claim: Option<bool>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    match config.protocol_admin.as_ref() {
+        Some(protocol_admin) if *protocol_admin == info.sender => {}
+        protocol_admin => {
+            return Err(ContractError::Unauthorized {
+                action: "update_protocol".to_string(),
+                expected: protocol_admin
+                    .map(|x| x.to_string())
+                    .unwrap_or_else(|| "<not assigned>".to_string()),
+                actual: info.sender.to_string(),
+            })
+        }
+    }
+
+    if let Some(v) = admin {
+        config.protocol_admin = Some(deps.api.addr_validate(v.as_str())?);
+    }
+
+    if let Some(v) = fee_rate {
+        config.protocol_fee_rate = v;
+    }
+
+    if let Some(v) = fee_collector {
+        config.protocol_fee_collector = Some(deps.api.addr_validate(v.as_str())?);
+    }
+
+    if let Some(v) = fee_on_claim {
+        config.protocol_fee_on_claim = v;
+    }
+
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_protocol")]))
+}
+
+pub fn update_liquidity_seed(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    liquidity_seed: Option<SwapLiquiditySeed>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    if State::load(deps.storage)?.liquidity_seeded {
+        return Err(ContractError::LiquidityAlreadyProvided {});
+    }
+
+    config.liquidity_seed = match liquidity_seed {
+        Some(seed) => Some(LiquiditySeed {
+            pair: deps.api.addr_validate(seed.pair.as_str())?,
+            input_ratio: seed.input_ratio,
+            output_amount: seed.output_amount,
+            lp_recipient: seed
+                .lp_recipient
+                .map(|x| deps.api.addr_validate(x.as_str()))
+                .transpose()?,
+        }),
+        None => None,
+    };
+
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_liquidity_seed")]))
+}
+
+pub fn update_deposit_route(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    asset: String,
+    pair: Option<String>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+
+    config.deposit_routes.retain(|(offer, _)| *offer != asset);
+    if let Some(v) = pair {
+        config
+            .deposit_routes
+            .push((asset, deps.api.addr_validate(v.as_str())?));
+    }
+
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_deposit_route")]))
+}
+
+pub fn update_referral_reward(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    reward: Option<SwapReferralReward>,
+) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+
+    config.referral_reward = reward.map(ReferralReward::from);
+
+    config.validate()?;
+    Config::save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_referral_reward")]))
+}
+
+pub fn accept_owner(deps: DepsMut, _env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let mut config = Config::load(deps.storage)?;
+    let resp = role::accept_owner::<Role>(deps.storage, &info.sender, &config.owner)?;
+    config.owner = info.sender;
+    Config::save(deps.storage, &config)?;
+
+    Ok(resp)
+}
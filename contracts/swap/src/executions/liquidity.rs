@@ -0,0 +1,186 @@
+use cosmwasm_std::{
+    attr, to_binary, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Denom};
+use pylon_gateway::pause::Pause;
+use pylon_utils::tax::deduct_tax;
+use terraswap::asset::{Asset, AssetInfo, PairInfo};
+use terraswap::pair::{ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg};
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::error::ContractError;
+use crate::executions::swap::is_refund_mode;
+use crate::states::config::Config;
+use crate::states::state::State;
+
+pub fn provide(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            action: "provide_liquidity".to_string(),
+            expected: config.owner.to_string(),
+            actual: info.sender.to_string(),
+        });
+    }
+
+    Pause::load(deps.storage)?.check_earn()?;
+
+    // same as earn. raised funds are refundable until then
+    if env.block.time.seconds() < config.finish + EARN_LOCK_PERIOD {
+        return Err(ContractError::NotAllowEarnBeforeLockPeriod {});
+    }
+
+    let mut state = State::load(deps.storage)?;
+    if is_refund_mode(&config, &state, env.block.time.seconds()) {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
+    if state.liquidity_seeded {
+        return Err(ContractError::LiquidityAlreadyProvided {});
+    }
+
+    let seed = match config.liquidity_seed {
+        Some(seed) => seed,
+        None => return Err(ContractError::LiquiditySeedNotConfigured {}),
+    };
+
+    let input_token = match config.input_token {
+        Denom::Native(input_token) => input_token,
+        Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
+    };
+    let output_token = match config.output_token {
+        Denom::Native(_) => unreachable!("native as output_token is not supported"),
+        Denom::Cw20(output_token) => output_token,
+    };
+
+    // swapped but unclaimed output tokens must stay for the users and referrers
+    let balance: BalanceResponse = deps.querier.query_wasm_smart(
+        output_token.to_string(),
+        &Cw20QueryMsg::Balance {
+            address: env.contract.address.to_string(),
+        },
+    )?;
+    let available = balance
+        .balance
+        .checked_sub(
+            state.total_swapped - state.total_claimed + state.total_referral_reward_out
+                - state.total_referral_reward_out_claimed,
+        )
+        .unwrap_or_default();
+    if available < seed.output_amount {
+        return Err(ContractError::InsufficientLiquiditySeedBalance { available });
+    }
+
+    let input = deduct_tax(
+        deps.as_ref(),
+        Coin {
+            denom: input_token.clone(),
+            amount: state.total_raised * seed.input_ratio,
+        },
+    )?;
+
+    state.liquidity_seeded = true;
+    state.liquidity_seed_input = state.total_raised * seed.input_ratio;
+    State::save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: output_token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                spender: seed.pair.to_string(),
+                amount: seed.output_amount,
+                expires: None,
+            })?,
+            funds: vec![],
+        }))
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: seed.pair.to_string(),
+            msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+                assets: [
+                    Asset {
+                        info: AssetInfo::NativeToken { denom: input_token },
+                        amount: input.amount,
+                    },
+                    Asset {
+                        info: AssetInfo::Token {
+                            contract_addr: output_token.to_string(),
+                        },
+                        amount: seed.output_amount,
+                    },
+                ],
+                slippage_tolerance: None,
+                receiver: seed.lp_recipient.map(|x| x.to_string()),
+            })?,
+            funds: vec![input.clone()],
+        }))
+        .add_attributes(vec![
+            attr("action", "provide_liquidity"),
+            attr("sender", info.sender.to_string()),
+            attr("input_amount", input.amount.to_string()),
+            attr("output_amount", seed.output_amount.to_string()),
+        ]))
+}
+
+pub fn release(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Option<Uint128>,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            action: "release_liquidity".to_string(),
+            expected: config.owner.to_string(),
+            actual: info.sender.to_string(),
+        });
+    }
+
+    Pause::load(deps.storage)?.check_earn()?;
+
+    let state = State::load(deps.storage)?;
+    if !state.liquidity_seeded {
+        return Err(ContractError::LiquidityNotProvided {});
+    }
+
+    let seed = match config.liquidity_seed {
+        Some(seed) => seed,
+        None => return Err(ContractError::LiquiditySeedNotConfigured {}),
+    };
+    let recipient = deps.api.addr_validate(recipient.as_str())?;
+
+    let pair: PairInfo = deps
+        .querier
+        .query_wasm_smart(seed.pair.to_string(), &PairQueryMsg::Pair {})?;
+    let amount = match amount {
+        Some(amount) => amount,
+        None => {
+            let balance: BalanceResponse = deps.querier.query_wasm_smart(
+                pair.liquidity_token.clone(),
+                &Cw20QueryMsg::Balance {
+                    address: env.contract.address.to_string(),
+                },
+            )?;
+            balance.balance
+        }
+    };
+    if amount.is_zero() {
+        return Err(ContractError::NotAllowZeroAmount {});
+    }
+
+    Ok(Response::new()
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pair.liquidity_token,
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }))
+        .add_attributes(vec![
+            attr("action", "release_liquidity"),
+            attr("sender", info.sender.to_string()),
+            attr("recipient", recipient.to_string()),
+            attr("amount", amount.to_string()),
+        ]))
+}
@@ -3,6 +3,9 @@ use cosmwasm_std::Response;
 use crate::error::ContractError;
 
 pub mod config;
+pub mod liquidity;
+pub mod referral;
+pub mod route;
 pub mod state;
 pub mod swap;
 pub mod user;
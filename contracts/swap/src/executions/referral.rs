@@ -0,0 +1,81 @@
+use cosmwasm_std::{
+    attr, to_binary, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Denom};
+use pylon_gateway::pause::Pause;
+use pylon_utils::tax::deduct_tax;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::error::ContractError;
+use crate::executions::swap::is_refund_mode;
+use crate::states::config::Config;
+use crate::states::referrer::Referrer;
+use crate::states::state::State;
+
+pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_claim()?;
+    let mut state = State::load(deps.storage)?;
+
+    // rewards are paid from funds which are refundable until then
+    if env.block.time.seconds() < config.finish + EARN_LOCK_PERIOD {
+        return Err(ContractError::NotAllowReferralClaimBeforeLockPeriod {});
+    }
+    if is_refund_mode(&config, &state, env.block.time.seconds()) {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
+
+    let sender = &deps.api.addr_canonicalize(info.sender.as_str())?;
+    let mut referrer = Referrer::load(deps.storage, sender);
+    let claimable_in = referrer.reward_in - referrer.reward_in_claimed;
+    let claimable_out = referrer.reward_out - referrer.reward_out_claimed;
+    if claimable_in.is_zero() && claimable_out.is_zero() {
+        return Err(ContractError::NotAllowZeroAmount {});
+    }
+
+    referrer.reward_in_claimed += claimable_in;
+    referrer.reward_out_claimed += claimable_out;
+    Referrer::save(deps.storage, sender, &referrer)?;
+
+    state.total_referral_reward_out_claimed += claimable_out;
+    State::save(deps.storage, &state)?;
+
+    let mut response = Response::new();
+    if !claimable_in.is_zero() {
+        let input_token = match config.input_token {
+            Denom::Native(input_token) => input_token,
+            Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
+        };
+        response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![deduct_tax(
+                deps.as_ref(),
+                Coin {
+                    denom: input_token,
+                    amount: claimable_in,
+                },
+            )?],
+        }));
+    }
+    if !claimable_out.is_zero() {
+        let output_token = match config.output_token {
+            Denom::Native(_) => unreachable!("native as output_token is not supported"),
+            Denom::Cw20(output_token) => output_token,
+        };
+        response = response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: output_token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount: claimable_out,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", "claim_referral_reward"),
+        attr("sender", info.sender.to_string()),
+        attr("amount_in", claimable_in.to_string()),
+        attr("amount_out", claimable_out.to_string()),
+    ]))
+}
@@ -0,0 +1,200 @@
+use cosmwasm_std::{
+    attr, from_binary, to_binary, Addr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, SubMsg, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, Denom};
+use pylon_gateway::pause::Pause;
+use pylon_gateway::swap_msg::Cw20HookMsg;
+use pylon_utils::tax::deduct_tax;
+use terraswap::asset::{Asset, AssetInfo};
+use terraswap::pair::{Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg};
+
+use crate::constants::DEPOSIT_ROUTE_REPLY_ID;
+use crate::error::ContractError;
+use crate::executions::swap::{check_swap_period, deposit_internal};
+use crate::states::config::Config;
+use crate::states::route::PendingDeposit;
+
+pub fn receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> super::ExecuteResult {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::DepositRouted {
+            min_receive,
+            referrer,
+        } => {
+            let sender = deps.api.addr_validate(cw20_msg.sender.as_str())?;
+            let referrer = referrer
+                .map(|x| deps.api.addr_validate(x.as_str()))
+                .transpose()?;
+            let config = Config::load(deps.storage)?;
+            let pair = route_of(&config, info.sender.as_str())?;
+
+            let swap_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: info.sender.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: pair.to_string(),
+                    amount: cw20_msg.amount,
+                    msg: to_binary(&PairCw20HookMsg::Swap {
+                        belief_price: None,
+                        max_spread: None,
+                        to: None,
+                    })?,
+                })?,
+                funds: vec![],
+            });
+
+            route(
+                deps,
+                env,
+                sender,
+                swap_msg,
+                info.sender.to_string(),
+                min_receive,
+                referrer,
+            )
+        }
+    }
+}
+
+pub fn deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    min_receive: Uint128,
+    referrer: Option<String>,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    let referrer = referrer
+        .map(|x| deps.api.addr_validate(x.as_str()))
+        .transpose()?;
+    let offer = match info.funds.as_slice() {
+        [coin] if !coin.amount.is_zero() => coin.clone(),
+        [] | [_] => return Err(ContractError::NotAllowZeroAmount {}),
+        [coin, ..] => {
+            return Err(ContractError::NotAllowOtherDenoms {
+                denom: coin.denom.clone(),
+            })
+        }
+    };
+    if config.input_token == Denom::Native(offer.denom.clone()) {
+        check_swap_period(&config, env.block.time.seconds())?;
+        return deposit_internal(
+            deps,
+            env,
+            info.sender.clone(),
+            info.sender,
+            offer.amount,
+            referrer,
+        );
+    }
+    let pair = route_of(&config, offer.denom.as_str())?;
+
+    let offer = deduct_tax(deps.as_ref(), offer)?;
+    let swap_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: pair.to_string(),
+        msg: to_binary(&PairExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: offer.denom.clone(),
+                },
+                amount: offer.amount,
+            },
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        })?,
+        funds: vec![offer.clone()],
+    });
+
+    route(
+        deps,
+        env,
+        info.sender,
+        swap_msg,
+        offer.denom,
+        min_receive,
+        referrer,
+    )
+}
+
+fn route_of(config: &Config, asset: &str) -> Result<Addr, ContractError> {
+    config
+        .deposit_route_of(asset)
+        .cloned()
+        .ok_or_else(|| ContractError::DepositRouteNotFound {
+            asset: asset.to_string(),
+        })
+}
+
+fn route(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    swap_msg: CosmosMsg,
+    asset: String,
+    min_receive: Uint128,
+    referrer: Option<Addr>,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    // fails before swapping, as the deposit after the swap would fail anyway
+    Pause::load(deps.storage)?.check_deposit()?;
+    check_swap_period(&config, env.block.time.seconds())?;
+
+    let balance = query_input_balance(deps.as_ref(), &env, &config)?;
+    PendingDeposit::save(
+        deps.storage,
+        &PendingDeposit {
+            sender: sender.clone(),
+            balance: balance.amount,
+            min_receive,
+            referrer,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(swap_msg, DEPOSIT_ROUTE_REPLY_ID))
+        .add_attributes(vec![
+            attr("action", "deposit_routed"),
+            attr("sender", sender.to_string()),
+            attr("asset", asset),
+        ]))
+}
+
+pub fn reply(deps: DepsMut, env: Env, _msg: Reply) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    let pending = PendingDeposit::load(deps.storage)?;
+    PendingDeposit::remove(deps.storage);
+
+    let balance = query_input_balance(deps.as_ref(), &env, &config)?;
+    let received = balance.amount.checked_sub(pending.balance)?;
+    if received.is_zero() || received < pending.min_receive {
+        return Err(ContractError::MinReceiveNotMet {
+            received,
+            min_receive: pending.min_receive,
+        });
+    }
+
+    deposit_internal(
+        deps,
+        env,
+        pending.sender.clone(),
+        pending.sender,
+        received,
+        pending.referrer,
+    )
+}
+
+fn query_input_balance(deps: Deps, env: &Env, config: &Config) -> Result<Coin, ContractError> {
+    let input_token = match &config.input_token {
+        Denom::Native(input_token) => input_token.clone(),
+        Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
+    };
+
+    Ok(deps
+        .querier
+        .query_balance(env.contract.address.clone(), input_token)?)
+}
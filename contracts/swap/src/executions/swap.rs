@@ -1,31 +1,29 @@
 use cosmwasm_std::{
-    attr, to_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, Fraction, MessageInfo,
-    Response, Uint128, WasmMsg,
+    attr, to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, Fraction,
+    MessageInfo, Response, StdResult, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Denom};
+use pylon_gateway::pause::Pause;
 use pylon_utils::tax::deduct_tax;
 use std::convert::TryFrom;
 
 use crate::constants::EARN_LOCK_PERIOD;
 use crate::error::ContractError;
 use crate::states::config::Config;
+use crate::states::referrer::{Referral, Referrer};
 use crate::states::state::State;
 use crate::states::user::User;
+use crate::types::distribution_strategy::DistributionStrategy;
 
-pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+pub fn deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    referrer: Option<String>,
+    recipient: Option<String>,
+) -> super::ExecuteResult {
     let config = Config::load(deps.storage)?;
-    let now = env.block.time.seconds();
-
-    if now < config.start {
-        return Err(ContractError::SwapNotStarted {
-            start: config.start,
-        });
-    }
-    if config.finish < now {
-        return Err(ContractError::SwapFinished {
-            finish: config.finish,
-        });
-    }
+    check_swap_period(&config, env.block.time.seconds())?;
 
     let input_token_denom = match config.input_token {
         Denom::Native(denom) => denom,
@@ -48,20 +46,80 @@ pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResu
         });
     }
 
-    let sender = &deps.api.addr_canonicalize(info.sender.as_str())?;
-    let mut user = User::load(deps.storage, sender);
+    let referrer = referrer
+        .map(|x| deps.api.addr_validate(x.as_str()))
+        .transpose()?;
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(recipient.as_str())?,
+        None => info.sender.clone(),
+    };
+
+    deposit_internal(deps, env, info.sender, recipient, swapped_in, referrer)
+}
+
+pub fn check_swap_period(config: &Config, now: u64) -> Result<(), ContractError> {
+    if now < config.start {
+        return Err(ContractError::SwapNotStarted {
+            start: config.start,
+        });
+    }
+    if config.finish < now {
+        return Err(ContractError::SwapFinished {
+            finish: config.finish,
+        });
+    }
+
+    Ok(())
+}
+
+// accounts input tokens already held by this contract to the recipient
+pub fn deposit_internal(
+    deps: DepsMut,
+    env: Env,
+    sender_addr: Addr,
+    recipient_addr: Addr,
+    swapped_in: Uint128,
+    referrer: Option<Addr>,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_deposit()?;
+    check_swap_period(&config, env.block.time.seconds())?;
+    if referrer.as_ref() == Some(&recipient_addr) || referrer.as_ref() == Some(&sender_addr) {
+        return Err(ContractError::NotAllowSelfReferral {});
+    }
+
+    let recipient = &deps.api.addr_canonicalize(recipient_addr.as_str())?;
+    let mut user = User::load(deps.storage, recipient);
+
+    // only the recipient can join a tier. a deposit for others keeps the schedule of both sides
+    let recipient_tier = User::load_tier(deps.storage, recipient);
+    if recipient_addr != sender_addr {
+        let sender = deps.api.addr_canonicalize(sender_addr.as_str())?;
+        if recipient_tier != User::load_tier(deps.storage, &sender)
+            || (config.deposit_tier.is_some() && recipient_tier.is_none())
+        {
+            return Err(ContractError::NotAllowDepositBetweenTiers {});
+        }
+    }
     let mut state = State::load(deps.storage)?;
+    if state.cancelled {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
 
     // check whitelisted, or free to participate everyone
-    if config.whitelist_enabled && !User::is_whitelisted(deps.storage, sender) {
+    if config.whitelist_enabled && !User::is_whitelisted(deps.storage, recipient) {
         return Err(ContractError::NotAllowNonWhitelisted {
-            address: info.sender.to_string(),
+            address: recipient_addr.to_string(),
         });
     }
 
     if let Some(strategy) = config.deposit_cap_strategy {
-        let (amount, unlimited) =
-            strategy.available_cap_of(deps.querier, info.sender.to_string(), user.swapped_in);
+        let (amount, unlimited) = strategy.available_cap_of(
+            deps.as_ref(),
+            env.block.time.seconds(),
+            recipient_addr.to_string(),
+            user.swapped_in,
+        )?;
         if !unlimited && swapped_in > amount {
             return Err(ContractError::AvailableCapExceeded { available: amount });
         }
@@ -69,9 +127,16 @@ pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResu
 
     let swapped_out = swapped_in * Uint128::from(config.price.denominator())
         / Uint128::from(config.price.numerator());
-    if state.total_swapped + swapped_out > config.amount {
+
+    // referral rewards in output token share the pool
+    let (reward_in, reward_out) = match (&referrer, &config.referral_reward) {
+        (Some(_), Some(reward)) => reward.reward_of(swapped_in, swapped_out),
+        _ => (Uint128::zero(), Uint128::zero()),
+    };
+    let reserved = state.total_swapped + state.total_referral_reward_out;
+    if reserved + swapped_out + reward_out > config.amount {
         return Err(ContractError::PoolSizeExceeded {
-            available: config.amount - state.total_swapped,
+            available: config.amount - reserved,
         });
     }
 
@@ -79,16 +144,59 @@ pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResu
     user.swapped_out += swapped_out;
 
     state.total_swapped += swapped_out;
+    state.total_raised += swapped_in;
 
-    User::save(deps.storage, sender, &user)?;
+    User::save(deps.storage, recipient, &user)?;
     State::save(deps.storage, &state)?;
 
-    Ok(Response::new().add_attributes(vec![
+    // users join the tier of the round they bought in
+    if config.deposit_tier.is_some() && recipient_tier.is_none() {
+        User::save_tier(deps.storage, recipient, &config.deposit_tier)?;
+    }
+
+    let mut response = Response::new().add_attributes(vec![
         attr("action", "deposit"),
-        attr("sender", info.sender.to_string()),
+        attr("sender", sender_addr.to_string()),
         attr("swapped_in", swapped_in.to_string()),
         attr("swapped_out", swapped_out.to_string()),
-    ]))
+    ]);
+    if recipient_addr != sender_addr {
+        response = response.add_attribute("recipient", recipient_addr.to_string());
+    }
+
+    if let Some(referrer_addr) = referrer {
+        let referrer = &deps.api.addr_canonicalize(referrer_addr.as_str())?;
+        let mut referral = Referrer::load(deps.storage, referrer);
+        referral.referred_in += swapped_in;
+        referral.referred_count += 1;
+        referral.reward_in += reward_in;
+        referral.reward_out += reward_out;
+        Referrer::save(deps.storage, referrer, &referral)?;
+
+        let mut referrals = Referral::load_of(deps.storage, recipient);
+        match referrals.iter_mut().find(|x| &x.referrer == referrer) {
+            Some(referral) => {
+                referral.swapped_in += swapped_in;
+                referral.reward_in += reward_in;
+                referral.reward_out += reward_out;
+            }
+            None => referrals.push(Referral {
+                referrer: referrer.clone(),
+                swapped_in,
+                reward_in,
+                reward_out,
+            }),
+        }
+        Referral::save_of(deps.storage, recipient, &referrals)?;
+
+        state.total_referral_reward_in += reward_in;
+        state.total_referral_reward_out += reward_out;
+        State::save(deps.storage, &state)?;
+
+        response = response.add_attribute("referrer", referrer_addr.to_string());
+    }
+
+    Ok(response)
 }
 
 pub fn withdraw(
@@ -99,18 +207,22 @@ pub fn withdraw(
 ) -> super::ExecuteResult {
     // xyk
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_withdraw()?;
     let now = env.block.time.seconds();
+    let sender = &deps.api.addr_canonicalize(info.sender.as_str()).unwrap();
     if config
-        .distribution_strategies
+        .distribution_strategies_of(&User::load_tier(deps.storage, sender))
         .iter()
         .all(|strategy| !strategy.check_release_time(&now))
     {
         return Err(ContractError::NotAllowWithdrawAfterRelease {});
     }
 
-    let sender = &deps.api.addr_canonicalize(info.sender.as_str()).unwrap();
     let mut user = User::load(deps.storage, sender);
     let mut state = State::load(deps.storage)?;
+    if is_refund_mode(&config, &state, now) {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
 
     if !user.swapped_out_claimed.is_zero() {
         return Err(ContractError::NotAllowWithdrawAfterClaim {});
@@ -128,17 +240,55 @@ pub fn withdraw(
     let withdraw_amount = calculate_withdraw_amount(&state, &amount);
     let penalty = (amount * config.price) - withdraw_amount;
 
+    // referrers lose the share of rewards accrued on the withdrawn amount.
+    // nothing is left to reverse once the whole position is transferred away
+    if !user.swapped_in.is_zero() {
+        let mut referrals = Referral::load_of(deps.storage, sender);
+        for referral in referrals.iter_mut() {
+            let reversed_in = referral
+                .swapped_in
+                .multiply_ratio(amount * config.price, user.swapped_in);
+            let reversed_reward_in = referral
+                .reward_in
+                .multiply_ratio(amount * config.price, user.swapped_in);
+            let reversed_reward_out = referral
+                .reward_out
+                .multiply_ratio(amount * config.price, user.swapped_in);
+            referral.swapped_in -= reversed_in;
+            referral.reward_in -= reversed_reward_in;
+            referral.reward_out -= reversed_reward_out;
+
+            // rewards which are claimed already can not be taken back
+            let mut referrer = Referrer::load(deps.storage, &referral.referrer);
+            let released_in =
+                reversed_reward_in.min(referrer.reward_in - referrer.reward_in_claimed);
+            let released_out =
+                reversed_reward_out.min(referrer.reward_out - referrer.reward_out_claimed);
+            referrer.referred_in = referrer.referred_in.checked_sub(reversed_in)?;
+            referrer.reward_in -= released_in;
+            referrer.reward_out -= released_out;
+            Referrer::save(deps.storage, &referral.referrer, &referrer)?;
+
+            state.total_referral_reward_in =
+                state.total_referral_reward_in.checked_sub(released_in)?;
+            state.total_referral_reward_out =
+                state.total_referral_reward_out.checked_sub(released_out)?;
+        }
+        Referral::save_of(deps.storage, sender, &referrals)?;
+    }
+
     user.swapped_out -= amount;
     user.swapped_in -= amount * config.price;
 
     state.total_swapped -= amount;
+    state.total_raised = state.total_raised.checked_sub(amount * config.price)?;
     state.x_liquidity -= withdraw_amount;
     state.y_liquidity += amount;
 
     User::save(deps.storage, sender, &user)?;
     State::save(deps.storage, &state)?;
 
-    let input_token = match config.input_token {
+    let input_token = match config.input_token.clone() {
         Denom::Native(input_token) => input_token,
         Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
     };
@@ -154,16 +304,12 @@ pub fn withdraw(
                 },
             )?],
         }))
-        .add_message(CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.beneficiary.to_string(),
-            amount: vec![deduct_tax(
-                deps.as_ref(),
-                Coin {
-                    denom: input_token,
-                    amount: penalty,
-                },
-            )?],
-        }))
+        .add_messages(split_payout(
+            deps.as_ref(),
+            &input_token,
+            penalty,
+            &config.penalty_beneficiaries_or_default(),
+        )?)
         .add_attributes(vec![
             attr("action", "withdraw"),
             attr("sender", info.sender.to_string()),
@@ -174,16 +320,28 @@ pub fn withdraw(
 
 pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_claim()?;
 
     let sender = &deps.api.addr_canonicalize(info.sender.as_str()).unwrap();
     let mut state = State::load(deps.storage)?;
     let mut user = User::load(deps.storage, sender);
+    if is_refund_mode(&config, &state, env.block.time.seconds()) {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
 
-    let claimable_token = calculate_claimable_tokens(&config, &user, env.block.time.seconds());
+    let claimable_token = calculate_claimable_tokens(
+        config.distribution_strategies_of(&User::load_tier(deps.storage, sender)),
+        &user,
+        env.block.time.seconds(),
+    )?;
+
+    let (_, fee_rate) = config.protocol_fee_rates();
+    let protocol_fee = claimable_token * fee_rate;
 
     user.swapped_out_claimed += claimable_token;
 
     state.total_claimed += claimable_token;
+    state.total_protocol_fee_output += protocol_fee;
 
     User::save(deps.storage, sender, &user)?;
     State::save(deps.storage, &state)?;
@@ -193,24 +351,41 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult
         Denom::Cw20(output_token) => output_token,
     };
 
-    Ok(Response::new()
-        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+    let mut response = Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: output_token.to_string(),
+        msg: to_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount: claimable_token - protocol_fee,
+        })?,
+        funds: vec![],
+    }));
+    if let (Some(collector), false) = (config.protocol_fee_collector, protocol_fee.is_zero()) {
+        response = response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: output_token.to_string(),
             msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: info.sender.to_string(),
-                amount: claimable_token,
+                recipient: collector.to_string(),
+                amount: protocol_fee,
             })?,
             funds: vec![],
-        }))
-        .add_attributes(vec![
-            attr("action", "claim"),
-            attr("sender", info.sender.to_string()),
-            attr("amount", claimable_token.to_string()),
-        ]))
+        }));
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", "claim"),
+        attr("sender", info.sender.to_string()),
+        attr("amount", claimable_token.to_string()),
+        attr("protocol_fee", protocol_fee.to_string()),
+    ]))
 }
 
-pub fn earn(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+pub fn earn(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+) -> super::ExecuteResult {
     let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_earn()?;
     if config.beneficiary != info.sender {
         return Err(ContractError::Unauthorized {
             action: "earn".to_string(),
@@ -223,6 +398,112 @@ pub fn earn(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult
         return Err(ContractError::NotAllowEarnBeforeLockPeriod {});
     }
 
+    let mut state = State::load(deps.storage)?;
+    if is_refund_mode(&config, &state, env.block.time.seconds()) {
+        return Err(ContractError::NotAllowInRefundMode {});
+    }
+    if config.liquidity_seed.is_some() && !state.liquidity_seeded {
+        return Err(ContractError::NotAllowEarnBeforeLiquidity {});
+    }
+
+    let input_token = match config.input_token.clone() {
+        Denom::Native(input_token) => input_token,
+        Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
+    };
+
+    // never exceeds the actual holdings of this contract
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, input_token.clone())?
+        .amount;
+    let earnable = std::cmp::min(calculate_earnable_amount(&config, &state), balance);
+    let amount = amount.unwrap_or(earnable);
+    if amount.is_zero() {
+        return Err(ContractError::NotAllowZeroAmount {});
+    }
+    if earnable < amount {
+        return Err(ContractError::EarnAmountExceeded {
+            available: earnable,
+        });
+    }
+
+    let (fee_rate, _) = config.protocol_fee_rates();
+    let protocol_fee = amount * fee_rate;
+
+    state.total_earned += amount;
+    state.total_protocol_fee_input += protocol_fee;
+    State::save(deps.storage, &state)?;
+
+    let mut response = Response::new();
+    if let (Some(collector), false) = (
+        config.protocol_fee_collector.as_ref(),
+        protocol_fee.is_zero(),
+    ) {
+        response = response.add_message(CosmosMsg::Bank(BankMsg::Send {
+            to_address: collector.to_string(),
+            amount: vec![deduct_tax(
+                deps.as_ref(),
+                Coin {
+                    denom: input_token.clone(),
+                    amount: protocol_fee,
+                },
+            )?],
+        }));
+    }
+
+    Ok(response
+        .add_messages(split_payout(
+            deps.as_ref(),
+            &input_token,
+            amount - protocol_fee,
+            &config.proceeds_beneficiaries_or_default(),
+        )?)
+        .add_attribute("action", "earn")
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("protocol_fee", protocol_fee.to_string())
+        .add_attribute(
+            "total_protocol_fee_input",
+            state.total_protocol_fee_input.to_string(),
+        )
+        .add_attribute(
+            "total_protocol_fee_output",
+            state.total_protocol_fee_output.to_string(),
+        ))
+}
+
+pub fn refund(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    Pause::load(deps.storage)?.check_withdraw()?;
+    let mut state = State::load(deps.storage)?;
+    if !is_refund_mode(&config, &state, env.block.time.seconds()) {
+        return Err(ContractError::NotAllowRefund {});
+    }
+
+    let sender = &deps.api.addr_canonicalize(info.sender.as_str())?;
+    let user = User::load(deps.storage, sender);
+    if user.swapped_out.is_zero() {
+        return Err(ContractError::NotAllowZeroAmount {});
+    }
+
+    // claimed portion is not refundable
+    let unclaimed = user.swapped_out - user.swapped_out_claimed;
+    let refund_amount = user.swapped_in.multiply_ratio(unclaimed, user.swapped_out);
+
+    state.total_swapped -= unclaimed;
+    state.total_refunded += refund_amount;
+
+    User::save(
+        deps.storage,
+        sender,
+        &User {
+            swapped_in: Uint128::zero(),
+            swapped_out: user.swapped_out_claimed,
+            swapped_out_claimed: user.swapped_out_claimed,
+        },
+    )?;
+    State::save(deps.storage, &state)?;
+
     let input_token = match config.input_token {
         Denom::Native(input_token) => input_token,
         Denom::Cw20(_) => unreachable!("cw20 as input_token is not supported"),
@@ -230,16 +511,195 @@ pub fn earn(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult
 
     Ok(Response::new()
         .add_message(CosmosMsg::Bank(BankMsg::Send {
-            to_address: config.beneficiary.to_string(),
+            to_address: info.sender.to_string(),
             amount: vec![deduct_tax(
                 deps.as_ref(),
-                deps.querier
-                    .query_balance(env.contract.address, input_token)
-                    .unwrap(),
+                Coin {
+                    denom: input_token,
+                    amount: refund_amount,
+                },
             )?],
         }))
-        .add_attribute("action", "earn")
-        .add_attribute("sender", info.sender.to_string()))
+        .add_attributes(vec![
+            attr("action", "refund"),
+            attr("sender", info.sender.to_string()),
+            attr("amount", refund_amount.to_string()),
+        ]))
+}
+
+pub fn cancel(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            action: "cancel".to_string(),
+            expected: config.owner.to_string(),
+            actual: info.sender.to_string(),
+        });
+    }
+
+    // raised funds may be already earned
+    if config.finish + EARN_LOCK_PERIOD <= env.block.time.seconds() {
+        return Err(ContractError::NotAllowCancelAfterLockPeriod {});
+    }
+
+    let mut state = State::load(deps.storage)?;
+    if state.cancelled {
+        return Err(ContractError::AlreadyCancelled {});
+    }
+    state.cancelled = true;
+    State::save(deps.storage, &state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "cancel"),
+        attr("sender", info.sender.to_string()),
+    ]))
+}
+
+pub fn transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    owner: String,
+    recipient: String,
+    amount: Uint128,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    match config.receipt_token {
+        Some(token) if token == info.sender => {}
+        token => {
+            return Err(ContractError::Unauthorized {
+                action: "transfer_internal".to_string(),
+                expected: token
+                    .map(|x| x.to_string())
+                    .unwrap_or_else(|| "<not assigned>".to_string()),
+                actual: info.sender.to_string(),
+            })
+        }
+    }
+    // moving a position out is a withdrawal for the owner
+    Pause::load(deps.storage)?.check_withdraw()?;
+
+    if owner != recipient {
+        let owner_addr = deps.api.addr_canonicalize(owner.as_str())?;
+        let recipient_addr = deps.api.addr_canonicalize(recipient.as_str())?;
+
+        let mut owner = User::load(deps.storage, &owner_addr);
+        let mut recipient = User::load(deps.storage, &recipient_addr);
+
+        let unclaimed = owner.swapped_out - owner.swapped_out_claimed;
+        if unclaimed < amount {
+            return Err(ContractError::TransferAmountExceeded {
+                available: unclaimed,
+            });
+        }
+
+        // recipient follows owner's vesting schedule
+        let owner_tier = User::load_tier(deps.storage, &owner_addr);
+        let recipient_tier = User::load_tier(deps.storage, &recipient_addr);
+        if owner_tier != recipient_tier {
+            if !recipient.swapped_out.is_zero() {
+                return Err(ContractError::NotAllowTransferBetweenTiers {});
+            }
+            User::save_tier(deps.storage, &recipient_addr, &owner_tier)?;
+        }
+
+        // move position proportionally to keep claimable ratio of both sides.
+        // owner keeps the rounded down claimed share, so it never ends with more claimed than released
+        if !amount.is_zero() {
+            let swapped_out_claimed = owner.swapped_out_claimed
+                - owner
+                    .swapped_out_claimed
+                    .multiply_ratio(unclaimed - amount, unclaimed);
+            let swapped_out = amount + swapped_out_claimed;
+            let swapped_in = owner
+                .swapped_in
+                .multiply_ratio(swapped_out, owner.swapped_out);
+
+            owner.swapped_in -= swapped_in;
+            owner.swapped_out -= swapped_out;
+            owner.swapped_out_claimed -= swapped_out_claimed;
+
+            recipient.swapped_in += swapped_in;
+            recipient.swapped_out += swapped_out;
+            recipient.swapped_out_claimed += swapped_out_claimed;
+        }
+
+        User::save(deps.storage, &owner_addr, &owner)?;
+        User::save(deps.storage, &recipient_addr, &recipient)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![attr("action", "transfer_internal")]))
+}
+
+// last recipient takes the remainder to avoid leaving dust
+fn split_payout(
+    deps: Deps,
+    denom: &str,
+    amount: Uint128,
+    beneficiaries: &[(Addr, Decimal)],
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut remaining = amount;
+    let mut msgs = vec![];
+    for (i, (address, share)) in beneficiaries.iter().enumerate() {
+        let portion = if i + 1 == beneficiaries.len() {
+            remaining
+        } else {
+            amount * *share
+        };
+        remaining -= portion;
+
+        // zero coins are rejected by the bank module
+        let coin = deduct_tax(
+            deps,
+            Coin {
+                denom: denom.to_string(),
+                amount: portion,
+            },
+        )?;
+        if coin.amount.is_zero() {
+            continue;
+        }
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: address.to_string(),
+            amount: vec![coin],
+        }));
+    }
+
+    Ok(msgs)
+}
+
+pub fn is_refund_mode(config: &Config, state: &State, time: u64) -> bool {
+    if state.cancelled {
+        return true;
+    }
+
+    match config.soft_cap {
+        Some(soft_cap) => config.finish < time && state.total_swapped < soft_cap,
+        None => false,
+    }
+}
+
+pub fn calculate_earnable_amount(config: &Config, state: &State) -> Uint128 {
+    let unlocked = if config.earn_by_claim {
+        if state.total_swapped.is_zero() {
+            Uint128::zero()
+        } else {
+            state
+                .total_raised
+                .multiply_ratio(state.total_claimed, state.total_swapped)
+        }
+    } else {
+        state.total_raised
+    };
+
+    unlocked
+        .checked_sub(
+            state.total_refunded
+                + state.total_earned
+                + state.liquidity_seed_input
+                + state.total_referral_reward_in,
+        )
+        .unwrap_or_else(|_| Uint128::zero())
 }
 
 pub fn calculate_withdraw_amount(state: &State, dy: &Uint128) -> Uint128 {
@@ -251,20 +711,25 @@ pub fn calculate_current_price(state: &State) -> Decimal {
     Decimal::from_ratio(state.x_liquidity, state.y_liquidity)
 }
 
-pub fn calculate_claimable_tokens(config: &Config, user: &User, time: u64) -> Uint128 {
-    let (count, mut ratio) = config.distribution_strategies.iter().fold(
+pub fn calculate_claimable_tokens(
+    strategies: &[DistributionStrategy],
+    user: &User,
+    time: u64,
+) -> Result<Uint128, ContractError> {
+    let (count, mut ratio) = strategies.iter().try_fold(
         (0u64, Decimal::zero()),
-        |(count, ratio), strategy| {
-            let (release_amount, fulfilled) = strategy.release_amount_at(&time);
-            (
+        |(count, ratio), strategy| -> Result<_, ContractError> {
+            let (release_amount, fulfilled) = strategy.release_amount_at(&time)?;
+            Ok((
                 count + if fulfilled { 1 } else { 0 },
                 ratio + release_amount,
-            )
+            ))
         },
-    );
-    if u64::try_from(config.distribution_strategies.len()).unwrap() == count {
+    )?;
+    if u64::try_from(strategies.len()).unwrap() == count {
         ratio = Decimal::one();
     }
 
-    (user.swapped_out * ratio) - user.swapped_out_claimed
+    // a transferred position may be claimed ahead of its schedule by rounding
+    Ok((user.swapped_out * ratio).saturating_sub(user.swapped_out_claimed))
 }
@@ -1,5 +1,10 @@
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Uint128};
+use pylon_token::gov_msg;
+use pylon_token::gov_resp;
+
+use crate::error::ContractError;
+use crate::states::config::Config;
 use crate::states::user::User;
-use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
 
 pub fn whitelist(
     deps: DepsMut,
@@ -20,3 +25,89 @@ pub fn whitelist(
 
     Ok(Response::new().add_attributes(vec![attr("action", "whitelist_user")]))
 }
+
+pub fn tier(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    tier: Option<String>,
+    candidates: Vec<String>,
+) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if let Some(tier) = tier.as_ref() {
+        if !config.has_tier(tier) {
+            return Err(ContractError::TierNotFound { tier: tier.clone() });
+        }
+    }
+
+    for address in candidates.iter() {
+        let candidate = deps.api.addr_canonicalize(address.as_str())?;
+        // claimed amount is bound to the current schedule
+        if !User::load(deps.storage, &candidate)
+            .swapped_out_claimed
+            .is_zero()
+        {
+            return Err(ContractError::NotAllowTierAfterClaim {
+                address: address.clone(),
+            });
+        }
+        User::save_tier(deps.storage, &candidate, &tier)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "tier_user"),
+        attr("tier", tier.unwrap_or_default()),
+    ]))
+}
+
+pub fn snapshot(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    stakes: Vec<(String, Uint128)>,
+) -> super::ExecuteResult {
+    for (address, stake) in stakes.iter() {
+        let owner = deps.api.addr_canonicalize(address.as_str())?;
+        User::save_stake_snapshot(deps.storage, &owner, stake)?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "snapshot_user"),
+        attr("count", stakes.len().to_string()),
+    ]))
+}
+
+pub fn register(deps: DepsMut, env: Env, info: MessageInfo) -> super::ExecuteResult {
+    let config = Config::load(deps.storage)?;
+    if config.start <= env.block.time.seconds() {
+        return Err(ContractError::NotAllowRegisterAfterStart {
+            start: config.start,
+        });
+    }
+
+    let contract = config
+        .deposit_cap_strategy
+        .and_then(|strategy| strategy.registration_contract(env.block.time.seconds()))
+        .ok_or(ContractError::RegistrationNotEnabled {})?;
+
+    // one-shot. a snapshot submitted by the owner is never overwritten
+    let sender = deps.api.addr_canonicalize(info.sender.as_str())?;
+    if User::load_stake_snapshot(deps.storage, &sender)?.is_some() {
+        return Err(ContractError::AlreadyRegistered {});
+    }
+
+    let staker: gov_resp::StakerResponse = deps.querier.query_wasm_smart(
+        contract,
+        &gov_msg::QueryMsg::Staker {
+            address: info.sender.to_string(),
+        },
+    )?;
+
+    User::save_stake_snapshot(deps.storage, &sender, &staker.balance)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "register"),
+        attr("sender", info.sender.to_string()),
+        attr("stake", staker.balance.to_string()),
+    ]))
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::{DepsMut, Env, Response};
+
+use crate::states::config::Config;
+use crate::states::state::State;
+
+pub fn migrate(deps: DepsMut, _env: Env) -> super::MigrateResult {
+    let config = Config::load(deps.storage)?;
+    let mut state = State::load(deps.storage)?;
+
+    // states saved before input token accounting read total_raised as zero
+    if state.total_raised.is_zero() && !state.total_swapped.is_zero() {
+        state.total_raised = state.total_swapped * config.price;
+        State::save(deps.storage, &state)?;
+    }
+
+    Ok(Response::default())
+}
@@ -2,6 +2,7 @@ use cosmwasm_std::Response;
 
 use crate::error::ContractError;
 
+pub mod general;
 pub mod nexus;
 pub mod pylon;
 pub mod valkyrie;
@@ -105,6 +105,8 @@ pub fn migrate(
         &Config {
             owner: api.addr_validate(legacy_config.owner.as_str())?,
             beneficiary: api.addr_validate(legacy_config.beneficiary.as_str())?,
+            proceeds_beneficiaries: vec![],
+            penalty_beneficiaries: vec![],
 
             start: legacy_config.start,
             finish: legacy_config.finish,
@@ -119,7 +121,19 @@ pub fn migrate(
                 .iter()
                 .map(|x| DistributionStrategy::from(x.clone()))
                 .collect(),
+            tier_distribution_strategies: vec![],
+            deposit_tier: None,
             whitelist_enabled: legacy_config.whitelist_enabled,
+            receipt_token: None,
+            soft_cap: None,
+            earn_by_claim: false,
+            protocol_admin: None,
+            protocol_fee_rate: Decimal::zero(),
+            protocol_fee_collector: None,
+            protocol_fee_on_claim: false,
+            liquidity_seed: None,
+            deposit_routes: vec![],
+            referral_reward: None,
         },
     )?;
 
@@ -131,6 +145,17 @@ pub fn migrate(
 
             x_liquidity: legacy_state.liq_x,
             y_liquidity: legacy_state.liq_y,
+            cancelled: false,
+            total_raised: legacy_state.total_swapped * legacy_config.price,
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero(),
         },
     )?;
 
@@ -1,7 +1,17 @@
-use cosmwasm_std::{to_binary, Deps, Env};
+use cosmwasm_std::{to_binary, Addr, Decimal, Deps, Env};
+use pylon_gateway::pause::{Pause, PauseEvent};
+use pylon_gateway::role::Roles;
+use pylon_gateway::swap_msg::Role;
+use pylon_gateway::swap_types::{
+    CapStrategy as SwapCapStrategy, DistributionStrategy as SwapDistributionStrategy,
+    LiquiditySeed as SwapLiquiditySeed,
+};
 use pylon_gateway::{swap_resp, swap_resp_v2};
 
+use crate::constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
 use crate::states::config::Config;
+use crate::types::cap_strategy::CapStrategy;
+use crate::types::distribution_strategy::DistributionStrategy;
 
 pub fn query_config(deps: Deps, _env: Env) -> super::QueryResult {
     let config = Config::load(deps.storage)?;
@@ -34,6 +44,131 @@ pub fn query_config_v2(deps: Deps, _env: Env) -> super::QueryResult {
             .iter()
             .map(|x| x.clone().into())
             .collect(),
+        tier_distribution_strategies: config
+            .tier_distribution_strategies
+            .into_iter()
+            .map(|(tier, strategies)| (tier, strategies.into_iter().map(|x| x.into()).collect()))
+            .collect(),
+        deposit_tier: config.deposit_tier,
         whitelist_enabled: config.whitelist_enabled,
+        receipt_token: config.receipt_token.map(|x| x.to_string()),
+        soft_cap: config.soft_cap,
+        earn_by_claim: config.earn_by_claim,
+        protocol_admin: config.protocol_admin.map(|x| x.to_string()),
+        protocol_fee_rate: config.protocol_fee_rate,
+        protocol_fee_collector: config.protocol_fee_collector.map(|x| x.to_string()),
+        protocol_fee_on_claim: config.protocol_fee_on_claim,
+        liquidity_seed: config.liquidity_seed.map(|seed| SwapLiquiditySeed {
+            pair: seed.pair.to_string(),
+            input_ratio: seed.input_ratio,
+            output_amount: seed.output_amount,
+            lp_recipient: seed.lp_recipient.map(|x| x.to_string()),
+        }),
+        deposit_routes: config
+            .deposit_routes
+            .into_iter()
+            .map(|(asset, pair)| (asset, pair.to_string()))
+            .collect(),
+        referral_reward: config.referral_reward.map(|x| x.into()),
+    })?)
+}
+
+pub fn query_cap_phase(deps: Deps, env: Env) -> super::QueryResult {
+    let config = Config::load(deps.storage)?;
+    let phase = config
+        .deposit_cap_strategy
+        .and_then(|strategy| strategy.current_phase(env.block.time.seconds()));
+
+    Ok(to_binary(&swap_resp_v2::CapPhaseResponse {
+        phase: phase.map(|(index, _, _)| index),
+        started_at: phase.map(|(_, start, _)| start),
+        next_phase_at: phase.and_then(|(_, _, next)| next),
+    })?)
+}
+
+pub fn query_beneficiaries(deps: Deps, _env: Env) -> super::QueryResult {
+    let config = Config::load(deps.storage)?;
+    let to_response = |beneficiaries: Vec<(Addr, Decimal)>| {
+        beneficiaries
+            .into_iter()
+            .map(|(address, share)| (address.to_string(), share))
+            .collect()
+    };
+
+    Ok(to_binary(&swap_resp_v2::BeneficiariesResponse {
+        proceeds: to_response(config.proceeds_beneficiaries_or_default()),
+        penalty: to_response(config.penalty_beneficiaries_or_default()),
+    })?)
+}
+
+pub fn query_roles(deps: Deps, _env: Env) -> super::QueryResult {
+    let config = Config::load(deps.storage)?;
+    let roles = Roles::<Role>::load(deps.storage)?;
+    let holder = |role: Role| roles.holder(&role).map(|x| x.to_string());
+
+    Ok(to_binary(&swap_resp_v2::RolesResponse {
+        owner: config.owner.to_string(),
+        pending_owner: roles.pending_owner.as_ref().map(|x| x.to_string()),
+        config_admin: holder(Role::ConfigAdmin),
+        whitelist_manager: holder(Role::WhitelistManager),
+        reward_manager: holder(Role::RewardManager),
+        guardian: holder(Role::Guardian),
+    })?)
+}
+
+pub fn query_pause(deps: Deps, _env: Env) -> super::QueryResult {
+    let pause = Pause::load(deps.storage)?;
+
+    Ok(to_binary(&swap_resp_v2::PauseResponse {
+        deposit: pause.deposit,
+        withdraw: pause.withdraw,
+        claim: pause.claim,
+        earn: pause.earn,
+    })?)
+}
+
+pub fn query_pause_history(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> super::QueryResult {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    Ok(to_binary(&swap_resp_v2::PauseHistoryResponse {
+        events: PauseEvent::load_range(deps.storage, start_after, limit)?
+            .into_iter()
+            .map(|(id, event)| swap_resp_v2::PauseEventResponse {
+                id,
+                action: event.action,
+                paused: event.paused,
+                sender: event.sender.to_string(),
+                time: event.time,
+            })
+            .collect(),
+    })?)
+}
+
+pub fn query_validate_config(
+    deps: Deps,
+    _env: Env,
+    deposit_cap_strategy: Option<SwapCapStrategy>,
+    distribution_strategies: Option<Vec<SwapDistributionStrategy>>,
+) -> super::QueryResult {
+    let mut config = Config::load(deps.storage)?;
+
+    if let Some(v) = deposit_cap_strategy {
+        config.deposit_cap_strategy = Some(CapStrategy::from(v));
+    }
+
+    if let Some(v) = distribution_strategies {
+        config.distribution_strategies = v.into_iter().map(DistributionStrategy::from).collect();
+    }
+
+    let error = config.validate().err();
+
+    Ok(to_binary(&swap_resp_v2::ValidateConfigResponse {
+        valid: error.is_none(),
+        error: error.map(|e| e.to_string()),
     })?)
 }
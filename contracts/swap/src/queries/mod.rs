@@ -3,6 +3,7 @@ use cosmwasm_std::Binary;
 use crate::error::ContractError;
 
 pub mod config;
+pub mod referrer;
 pub mod state;
 pub mod swap;
 pub mod user;
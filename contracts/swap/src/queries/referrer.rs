@@ -0,0 +1,36 @@
+use cosmwasm_std::{to_binary, Deps, Env, StdResult};
+use pylon_gateway::swap_resp_v2::{ReferrerResponse, TopReferrersResponse};
+
+use crate::states::referrer::Referrer;
+
+fn to_response(address: String, referrer: Referrer) -> ReferrerResponse {
+    ReferrerResponse {
+        address,
+        referred_in: referrer.referred_in,
+        referred_count: referrer.referred_count,
+        reward_in: referrer.reward_in,
+        reward_in_claimed: referrer.reward_in_claimed,
+        reward_out: referrer.reward_out,
+        reward_out_claimed: referrer.reward_out_claimed,
+    }
+}
+
+pub fn query_referrer(deps: Deps, _env: Env, address: String) -> super::QueryResult {
+    let referrer = Referrer::load(deps.storage, &deps.api.addr_canonicalize(address.as_str())?);
+
+    Ok(to_binary(&to_response(address, referrer))?)
+}
+
+pub fn query_top_referrers(deps: Deps, _env: Env, limit: Option<u32>) -> super::QueryResult {
+    let referrers = Referrer::load_top(deps.storage, limit)
+        .into_iter()
+        .map(|(address, referrer)| {
+            Ok(to_response(
+                deps.api.addr_humanize(&address)?.to_string(),
+                referrer,
+            ))
+        })
+        .collect::<StdResult<Vec<ReferrerResponse>>>()?;
+
+    Ok(to_binary(&TopReferrersResponse { referrers })?)
+}
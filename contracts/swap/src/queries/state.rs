@@ -1,15 +1,37 @@
-use cosmwasm_std::{to_binary, Deps, Env};
+use cosmwasm_std::{to_binary, Deps, Env, Uint128};
 use pylon_gateway::swap_resp::TotalSupplyResponse;
 use pylon_gateway::swap_resp_v2::StateResponse;
 
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::executions::swap::{calculate_earnable_amount, is_refund_mode};
+use crate::states::config::Config;
 use crate::states::state::State;
 
-pub fn query_state(deps: Deps, _env: Env) -> super::QueryResult {
+pub fn query_state(deps: Deps, env: Env) -> super::QueryResult {
+    let config = Config::load(deps.storage)?;
     let state = State::load(deps.storage)?;
+    let now = env.block.time.seconds();
+    let refund_mode = is_refund_mode(&config, &state, now);
 
     Ok(to_binary(&StateResponse {
         total_swapped: state.total_swapped,
         total_claimed: state.total_claimed,
+        cancelled: state.cancelled,
+        refund_mode,
+        total_raised: state.total_raised,
+        total_refunded: state.total_refunded,
+        total_earned: state.total_earned,
+        total_protocol_fee_input: state.total_protocol_fee_input,
+        total_protocol_fee_output: state.total_protocol_fee_output,
+        liquidity_seeded: state.liquidity_seeded,
+        liquidity_seed_input: state.liquidity_seed_input,
+        total_referral_reward_in: state.total_referral_reward_in,
+        total_referral_reward_out: state.total_referral_reward_out,
+        earnable: if refund_mode || now < config.finish + EARN_LOCK_PERIOD {
+            Uint128::zero()
+        } else {
+            calculate_earnable_amount(&config, &state)
+        },
     })?)
 }
 
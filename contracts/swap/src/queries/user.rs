@@ -1,30 +1,35 @@
-use cosmwasm_std::{to_binary, Deps, Env, QuerierWrapper};
+use cosmwasm_std::{to_binary, Deps, Env};
 use pylon_gateway::swap_resp::{
     AvailableCapOfResponse, BalanceOfResponse, ClaimableTokenOfResponse, IsWhitelistedResponse,
 };
-use pylon_gateway::swap_resp_v2::{UserResponse, UsersResponse};
+use pylon_gateway::swap_resp_v2::{StakeSnapshotOfResponse, UserResponse, UsersResponse};
 use pylon_utils::common::OrderBy;
 
+use crate::error::ContractError;
 use crate::executions::swap::calculate_claimable_tokens;
 use crate::states::config::Config;
 use crate::states::user::User;
 
 fn to_response(
-    querier: QuerierWrapper,
+    deps: Deps,
     config: &Config,
     user: &User,
     whitelisted: bool,
+    tier: Option<String>,
     address: String,
     time: u64,
-) -> UserResponse {
-    let claimable_token = calculate_claimable_tokens(config, user, time);
+) -> Result<UserResponse, ContractError> {
+    let claimable_token =
+        calculate_claimable_tokens(config.distribution_strategies_of(&tier), user, time)?;
 
-    UserResponse {
+    Ok(UserResponse {
         whitelisted,
+        tier,
         swapped_in: user.swapped_in,
         available_cap: match config.deposit_cap_strategy.clone() {
             Some(strategy) => {
-                let (cap, unlimited) = strategy.available_cap_of(querier, address, user.swapped_in);
+                let (cap, unlimited) =
+                    strategy.available_cap_of(deps, time, address, user.swapped_in)?;
                 if unlimited {
                     None
                 } else {
@@ -35,23 +40,25 @@ fn to_response(
         },
         reward_total: claimable_token,
         reward_remaining: user.swapped_out - (user.swapped_out_claimed + claimable_token),
-    }
+    })
 }
 
 pub fn query_user(deps: Deps, env: Env, address: String) -> super::QueryResult {
     let user_addr = deps.api.addr_canonicalize(address.as_str())?;
     let user = User::load(deps.storage, &user_addr);
     let whitelisted = User::is_whitelisted(deps.storage, &user_addr);
+    let tier = User::load_tier(deps.storage, &user_addr);
     let config = Config::load(deps.storage)?;
 
     Ok(to_binary(&to_response(
-        deps.querier,
+        deps,
         &config,
         &user,
         whitelisted,
+        tier,
         address,
         env.block.time.seconds(),
-    ))?)
+    )?)?)
 }
 
 pub fn query_users(
@@ -70,22 +77,26 @@ pub fn query_users(
         order,
     )
     .iter()
-    .map(|(user_addr, user)| -> (String, UserResponse) {
-        let whitelisted = User::is_whitelisted(deps.storage, user_addr);
-        let user_addr = api.addr_humanize(user_addr).unwrap();
-        (
-            user_addr.to_string(),
-            to_response(
-                deps.querier,
-                &config,
-                user,
-                whitelisted,
+    .map(
+        |(user_addr, user)| -> Result<(String, UserResponse), ContractError> {
+            let whitelisted = User::is_whitelisted(deps.storage, user_addr);
+            let tier = User::load_tier(deps.storage, user_addr);
+            let user_addr = api.addr_humanize(user_addr).unwrap();
+            Ok((
                 user_addr.to_string(),
-                env.block.time.seconds(),
-            ),
-        )
-    })
-    .collect();
+                to_response(
+                    deps,
+                    &config,
+                    user,
+                    whitelisted,
+                    tier,
+                    user_addr.to_string(),
+                    env.block.time.seconds(),
+                )?,
+            ))
+        },
+    )
+    .collect::<Result<_, ContractError>>()?;
 
     Ok(to_binary(&UsersResponse { users })?)
 }
@@ -106,15 +117,19 @@ pub fn query_is_whitelisted(deps: Deps, _env: Env, address: String) -> super::Qu
     Ok(to_binary(&IsWhitelistedResponse { whitelisted })?)
 }
 
-pub fn query_available_cap_of(deps: Deps, _env: Env, address: String) -> super::QueryResult {
+pub fn query_available_cap_of(deps: Deps, env: Env, address: String) -> super::QueryResult {
     let user_addr = deps.api.addr_canonicalize(address.as_str())?;
     let user = User::load(deps.storage, &user_addr);
     let config = Config::load(deps.storage)?;
 
-    let querier = deps.querier;
     let available_cap = match config.deposit_cap_strategy {
         Some(strategy) => {
-            let (cap, unlimited) = strategy.available_cap_of(querier, address, user.swapped_in);
+            let (cap, unlimited) = strategy.available_cap_of(
+                deps,
+                env.block.time.seconds(),
+                address,
+                user.swapped_in,
+            )?;
             if unlimited {
                 None
             } else {
@@ -130,12 +145,27 @@ pub fn query_available_cap_of(deps: Deps, _env: Env, address: String) -> super::
     })?)
 }
 
-pub fn query_claimable_token_of(deps: Deps, _env: Env, address: String) -> super::QueryResult {
+pub fn query_claimable_token_of(deps: Deps, env: Env, address: String) -> super::QueryResult {
     let user_addr = deps.api.addr_canonicalize(address.as_str())?;
     let user = User::load(deps.storage, &user_addr);
+    let config = Config::load(deps.storage)?;
+
+    let claimable_token = calculate_claimable_tokens(
+        config.distribution_strategies_of(&User::load_tier(deps.storage, &user_addr)),
+        &user,
+        env.block.time.seconds(),
+    )?;
 
     Ok(to_binary(&ClaimableTokenOfResponse {
-        amount: user.swapped_out,
-        remaining: user.swapped_out - user.swapped_out_claimed,
+        amount: claimable_token,
+        remaining: user.swapped_out - (user.swapped_out_claimed + claimable_token),
+    })?)
+}
+
+pub fn query_stake_snapshot_of(deps: Deps, _env: Env, address: String) -> super::QueryResult {
+    let user_addr = deps.api.addr_canonicalize(address.as_str())?;
+
+    Ok(to_binary(&StakeSnapshotOfResponse {
+        amount: User::load_stake_snapshot(deps.storage, &user_addr)?,
     })?)
 }
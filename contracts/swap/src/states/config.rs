@@ -4,14 +4,21 @@ use cw20::Denom;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::error::ContractError;
 use crate::types::cap_strategy::CapStrategy;
 use crate::types::distribution_strategy::DistributionStrategy;
+use crate::types::referral_reward::ReferralReward;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
     // accounts
     pub owner: Addr,
     pub beneficiary: Addr,
+    // weighted splits, falls back to the beneficiary if empty
+    #[serde(default)]
+    pub proceeds_beneficiaries: Vec<(Addr, Decimal)>,
+    #[serde(default)]
+    pub penalty_beneficiaries: Vec<(Addr, Decimal)>,
     // details
     pub start: u64,
     pub finish: u64,
@@ -22,7 +29,45 @@ pub struct Config {
     // strategies
     pub deposit_cap_strategy: Option<CapStrategy>,
     pub distribution_strategies: Vec<DistributionStrategy>,
+    #[serde(default)]
+    pub tier_distribution_strategies: Vec<(String, Vec<DistributionStrategy>)>,
+    #[serde(default)]
+    pub deposit_tier: Option<String>,
     pub whitelist_enabled: bool,
+    // transferable claim rights
+    #[serde(default)]
+    pub receipt_token: Option<Addr>,
+    #[serde(default)]
+    pub soft_cap: Option<Uint128>,
+    // unlocks earnings in proportion to claimed output tokens
+    #[serde(default)]
+    pub earn_by_claim: bool,
+    // protocol fee, managed by the protocol admin
+    #[serde(default)]
+    pub protocol_admin: Option<Addr>,
+    #[serde(default)]
+    pub protocol_fee_rate: Decimal,
+    #[serde(default)]
+    pub protocol_fee_collector: Option<Addr>,
+    // charges on output token at claim instead of input token at earn
+    #[serde(default)]
+    pub protocol_fee_on_claim: bool,
+    // post-sale liquidity provision
+    #[serde(default)]
+    pub liquidity_seed: Option<LiquiditySeed>,
+    // (offer denom or cw20 address, terraswap pair) to swap into input token
+    #[serde(default)]
+    pub deposit_routes: Vec<(String, Addr)>,
+    #[serde(default)]
+    pub referral_reward: Option<ReferralReward>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LiquiditySeed {
+    pub pair: Addr,
+    pub input_ratio: Decimal,
+    pub output_amount: Uint128,
+    pub lp_recipient: Option<Addr>,
 }
 
 impl Config {
@@ -33,4 +78,98 @@ impl Config {
     pub fn save(storage: &mut dyn Storage, data: &Self) -> StdResult<()> {
         Singleton::<Self>::new(storage, super::KEY_CONFIG).save(data)
     }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let Some(strategy) = &self.deposit_cap_strategy {
+            strategy.validate()?;
+        }
+        DistributionStrategy::validate_all(&self.distribution_strategies)?;
+        validate_shares(&self.proceeds_beneficiaries)?;
+        validate_shares(&self.penalty_beneficiaries)?;
+        if Decimal::one() < self.protocol_fee_rate
+            || (!self.protocol_fee_rate.is_zero() && self.protocol_fee_collector.is_none())
+        {
+            return Err(ContractError::InvalidProtocolFee {
+                rate: self.protocol_fee_rate,
+            });
+        }
+        if let Some(reward) = &self.referral_reward {
+            reward.validate()?;
+        }
+        if let Some(seed) = &self.liquidity_seed {
+            if Decimal::one() < seed.input_ratio {
+                return Err(ContractError::InvalidLiquiditySeedRatio {
+                    ratio: seed.input_ratio,
+                });
+            }
+        }
+        self.tier_distribution_strategies
+            .iter()
+            .try_for_each(|(_, strategies)| DistributionStrategy::validate_all(strategies))
+    }
+
+    pub fn deposit_route_of(&self, asset: &str) -> Option<&Addr> {
+        self.deposit_routes
+            .iter()
+            .find(|(offer, _)| offer == asset)
+            .map(|(_, pair)| pair)
+    }
+
+    pub fn has_tier(&self, tier: &str) -> bool {
+        self.tier_distribution_strategies
+            .iter()
+            .any(|(name, _)| name == tier)
+    }
+
+    // (fee on input, fee on output)
+    pub fn protocol_fee_rates(&self) -> (Decimal, Decimal) {
+        if self.protocol_fee_on_claim {
+            (Decimal::zero(), self.protocol_fee_rate)
+        } else {
+            (self.protocol_fee_rate, Decimal::zero())
+        }
+    }
+
+    pub fn proceeds_beneficiaries_or_default(&self) -> Vec<(Addr, Decimal)> {
+        self.beneficiaries_or_default(&self.proceeds_beneficiaries)
+    }
+
+    pub fn penalty_beneficiaries_or_default(&self) -> Vec<(Addr, Decimal)> {
+        self.beneficiaries_or_default(&self.penalty_beneficiaries)
+    }
+
+    fn beneficiaries_or_default(&self, beneficiaries: &[(Addr, Decimal)]) -> Vec<(Addr, Decimal)> {
+        if beneficiaries.is_empty() {
+            vec![(self.beneficiary.clone(), Decimal::one())]
+        } else {
+            beneficiaries.to_vec()
+        }
+    }
+
+    // falls back to global strategies if tier not found
+    pub fn distribution_strategies_of(&self, tier: &Option<String>) -> &[DistributionStrategy] {
+        tier.as_ref()
+            .and_then(|tier| {
+                self.tier_distribution_strategies
+                    .iter()
+                    .find(|(name, _)| name == tier)
+            })
+            .map(|(_, strategies)| strategies.as_slice())
+            .unwrap_or(&self.distribution_strategies)
+    }
+}
+
+fn validate_shares(beneficiaries: &[(Addr, Decimal)]) -> Result<(), ContractError> {
+    if beneficiaries.is_empty() {
+        return Ok(());
+    }
+
+    let sum = beneficiaries
+        .iter()
+        .fold(Decimal::zero(), |sum, (_, share)| sum + *share);
+    if sum != Decimal::one() || beneficiaries.iter().any(|(_, share)| share.is_zero()) {
+        return Err(ContractError::InvalidBeneficiaryShares { sum });
+    }
+
+    Ok(())
 }
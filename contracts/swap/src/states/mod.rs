@@ -1,9 +1,18 @@
 pub mod config;
+pub mod referrer;
+pub mod route;
 pub mod state;
 pub mod user;
 
 pub static KEY_STATE: &[u8] = b"state";
 pub static KEY_CONFIG: &[u8] = b"config";
+pub static KEY_PENDING_DEPOSIT: &[u8] = b"pending-deposit";
 pub static PREFIX_USER: &[u8] = b"user";
 pub static PREFIX_USER_INDEX: &[u8] = b"index-user";
+pub static PREFIX_REFERRER: &[u8] = b"referrer";
+pub static PREFIX_REFERRAL: &[u8] = b"referral";
+pub static PREFIX_REFERRER_RANK: &[u8] = b"index-referrer-rank";
 pub static PREFIX_WHITELIST: &[u8] = b"whitelist";
+pub static PREFIX_STAKE_SNAPSHOT: &[u8] = b"stake-snapshot";
+pub static PREFIX_TIER: &[u8] = b"tier";
+pub static PREFIX_TIER_COUNT: &[u8] = b"tier-count";
@@ -0,0 +1,91 @@
+use cosmwasm_std::{CanonicalAddr, Order, StdResult, Storage, Uint128};
+use cosmwasm_storage::{Bucket, ReadonlyBucket};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Referrer {
+    pub referred_in: Uint128,
+    pub referred_count: u64,
+    pub reward_in: Uint128,
+    pub reward_in_claimed: Uint128,
+    pub reward_out: Uint128,
+    pub reward_out_claimed: Uint128,
+}
+
+// what a user has been referred with, so it can be reversed on withdraw
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Referral {
+    pub referrer: CanonicalAddr,
+    pub swapped_in: Uint128,
+    pub reward_in: Uint128,
+    pub reward_out: Uint128,
+}
+
+impl Referrer {
+    pub fn load(storage: &dyn Storage, owner: &CanonicalAddr) -> Self {
+        ReadonlyBucket::<Self>::new(storage, super::PREFIX_REFERRER)
+            .load(owner.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn save(
+        storage: &mut dyn Storage,
+        owner: &CanonicalAddr,
+        referrer: &Self,
+    ) -> StdResult<()> {
+        let prev = Self::load(storage, owner);
+        let mut rank = Bucket::<bool>::new(storage, super::PREFIX_REFERRER_RANK);
+        rank.remove(&rank_key(&prev.referred_in, owner));
+        rank.save(&rank_key(&referrer.referred_in, owner), &true)?;
+
+        Bucket::<Self>::new(storage, super::PREFIX_REFERRER).save(owner.as_slice(), referrer)
+    }
+
+    // ordered by referred_in, descending
+    pub fn load_top(storage: &dyn Storage, limit: Option<u32>) -> Vec<(CanonicalAddr, Self)> {
+        let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+        ReadonlyBucket::<bool>::new(storage, super::PREFIX_REFERRER_RANK)
+            .range(None, None, Order::Descending)
+            .take(limit)
+            .map(|item| {
+                let (k, _) = item.unwrap();
+                let owner = CanonicalAddr::from(&k[16..]);
+                let referrer = Self::load(storage, &owner);
+                (owner, referrer)
+            })
+            .collect()
+    }
+}
+
+impl Referral {
+    pub fn load_of(storage: &dyn Storage, user: &CanonicalAddr) -> Vec<Self> {
+        ReadonlyBucket::<Vec<Self>>::multilevel(
+            storage,
+            &[super::PREFIX_USER_INDEX, super::PREFIX_REFERRAL],
+        )
+        .load(user.as_slice())
+        .unwrap_or_default()
+    }
+
+    pub fn save_of(
+        storage: &mut dyn Storage,
+        user: &CanonicalAddr,
+        referrals: &[Self],
+    ) -> StdResult<()> {
+        Bucket::<Vec<Self>>::multilevel(
+            storage,
+            &[super::PREFIX_USER_INDEX, super::PREFIX_REFERRAL],
+        )
+        .save(user.as_slice(), &referrals.to_vec())
+    }
+}
+
+fn rank_key(referred_in: &Uint128, owner: &CanonicalAddr) -> Vec<u8> {
+    let mut key = referred_in.u128().to_be_bytes().to_vec();
+    key.extend_from_slice(owner.as_slice());
+    key
+}
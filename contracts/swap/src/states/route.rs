@@ -0,0 +1,28 @@
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_storage::{ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// deposit waiting for a terraswap swap to finish
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingDeposit {
+    pub sender: Addr,
+    pub balance: Uint128, // input token balance before swap
+    pub min_receive: Uint128,
+    #[serde(default)]
+    pub referrer: Option<Addr>,
+}
+
+impl PendingDeposit {
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        ReadonlySingleton::<Self>::new(storage, super::KEY_PENDING_DEPOSIT).load()
+    }
+
+    pub fn save(storage: &mut dyn Storage, data: &Self) -> StdResult<()> {
+        Singleton::<Self>::new(storage, super::KEY_PENDING_DEPOSIT).save(data)
+    }
+
+    pub fn remove(storage: &mut dyn Storage) {
+        Singleton::<Self>::new(storage, super::KEY_PENDING_DEPOSIT).remove()
+    }
+}
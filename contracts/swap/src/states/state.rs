@@ -10,6 +10,37 @@ pub struct State {
 
     pub x_liquidity: Uint128,
     pub y_liquidity: Uint128,
+
+    #[serde(default)]
+    pub cancelled: bool,
+
+    // input token accounting
+    #[serde(default)]
+    pub total_raised: Uint128,
+    #[serde(default)]
+    pub total_refunded: Uint128,
+    #[serde(default)]
+    pub total_earned: Uint128,
+
+    // protocol fee accounting
+    #[serde(default)]
+    pub total_protocol_fee_input: Uint128,
+    #[serde(default)]
+    pub total_protocol_fee_output: Uint128,
+
+    // input tokens sent to the terraswap pair
+    #[serde(default)]
+    pub liquidity_seeded: bool,
+    #[serde(default)]
+    pub liquidity_seed_input: Uint128,
+
+    // accrued referral rewards
+    #[serde(default)]
+    pub total_referral_reward_in: Uint128,
+    #[serde(default)]
+    pub total_referral_reward_out: Uint128,
+    #[serde(default)]
+    pub total_referral_reward_out_claimed: Uint128,
 }
 
 impl State {
@@ -79,4 +79,74 @@ impl User {
         .load(owner.as_slice())
         .unwrap_or_default()
     }
+
+    pub fn save_stake_snapshot(
+        storage: &mut dyn Storage,
+        owner: &CanonicalAddr,
+        stake: &Uint128,
+    ) -> StdResult<()> {
+        Bucket::<Uint128>::multilevel(
+            storage,
+            &[super::PREFIX_USER_INDEX, super::PREFIX_STAKE_SNAPSHOT],
+        )
+        .save(owner.as_slice(), stake)
+    }
+
+    pub fn load_stake_snapshot(
+        storage: &dyn Storage,
+        owner: &CanonicalAddr,
+    ) -> StdResult<Option<Uint128>> {
+        ReadonlyBucket::<Uint128>::multilevel(
+            storage,
+            &[super::PREFIX_USER_INDEX, super::PREFIX_STAKE_SNAPSHOT],
+        )
+        .may_load(owner.as_slice())
+    }
+
+    pub fn save_tier(
+        storage: &mut dyn Storage,
+        owner: &CanonicalAddr,
+        tier: &Option<String>,
+    ) -> StdResult<()> {
+        let prev = Self::load_tier(storage, owner);
+        if prev == *tier {
+            return Ok(());
+        }
+        if let Some(prev) = prev.as_ref() {
+            let count = Self::count_tier(storage, prev);
+            Bucket::<u64>::new(storage, super::PREFIX_TIER_COUNT)
+                .save(prev.as_bytes(), &count.saturating_sub(1))?;
+        }
+        if let Some(tier) = tier.as_ref() {
+            let count = Self::count_tier(storage, tier);
+            Bucket::<u64>::new(storage, super::PREFIX_TIER_COUNT)
+                .save(tier.as_bytes(), &(count + 1))?;
+        }
+
+        let mut bucket =
+            Bucket::<String>::multilevel(storage, &[super::PREFIX_USER_INDEX, super::PREFIX_TIER]);
+        match tier {
+            Some(tier) => bucket.save(owner.as_slice(), tier),
+            None => {
+                bucket.remove(owner.as_slice());
+                Ok(())
+            }
+        }
+    }
+
+    // number of users in the tier
+    pub fn count_tier(storage: &dyn Storage, tier: &str) -> u64 {
+        ReadonlyBucket::<u64>::new(storage, super::PREFIX_TIER_COUNT)
+            .load(tier.as_bytes())
+            .unwrap_or_default()
+    }
+
+    pub fn load_tier(storage: &dyn Storage, owner: &CanonicalAddr) -> Option<String> {
+        ReadonlyBucket::<String>::multilevel(
+            storage,
+            &[super::PREFIX_USER_INDEX, super::PREFIX_TIER],
+        )
+        .may_load(owner.as_slice())
+        .unwrap()
+    }
 }
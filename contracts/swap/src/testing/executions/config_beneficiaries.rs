@@ -0,0 +1,182 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, from_binary, BankMsg, CosmosMsg, Decimal, Env, MessageInfo, SubMsg, Timestamp,
+};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, QueryMsg};
+use pylon_gateway::swap_resp_v2::BeneficiariesResponse;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::testing::{
+    instantiate, mock_deps, mock_deps_with_balance, MockDeps, TEST_BENEFICIARY, TEST_INPUT_TOKEN,
+    TEST_OWNER, TEST_USER_1, TEST_USER_2,
+};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    proceeds: Option<Vec<(String, Decimal)>>,
+    penalty: Option<Vec<(String, Decimal)>>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::Beneficiaries { proceeds, penalty })),
+    )
+}
+
+fn query_beneficiaries(deps: &MockDeps) -> BeneficiariesResponse {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Beneficiaries {}).unwrap()).unwrap()
+}
+
+#[test]
+fn success() {
+    const SWAP_IN_AMOUNT: u128 = 100;
+    let default_msg = instantiate::default_msg();
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    instantiate::default(&mut deps);
+
+    // defaults to the beneficiary
+    assert_eq!(
+        query_beneficiaries(&deps),
+        BeneficiariesResponse {
+            proceeds: vec![(TEST_BENEFICIARY.to_string(), Decimal::one())],
+            penalty: vec![(TEST_BENEFICIARY.to_string(), Decimal::one())],
+        }
+    );
+
+    let resp = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(vec![
+            (TEST_USER_1.to_string(), Decimal::percent(70)),
+            (TEST_USER_2.to_string(), Decimal::percent(30)),
+        ]),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![attr("action", "update_beneficiaries")]
+    );
+    assert_eq!(
+        query_beneficiaries(&deps),
+        BeneficiariesResponse {
+            proceeds: vec![
+                (TEST_USER_1.to_string(), Decimal::percent(70)),
+                (TEST_USER_2.to_string(), Decimal::percent(30)),
+            ],
+            penalty: vec![(TEST_BENEFICIARY.to_string(), Decimal::one())],
+        }
+    );
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN)),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    let resp = super::swap_earn::exec(&mut deps, env, mock_info(TEST_BENEFICIARY, &[])).unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: TEST_USER_1.to_string(),
+                amount: coins(70, TEST_INPUT_TOKEN)
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: TEST_USER_2.to_string(),
+                amount: coins(30, TEST_INPUT_TOKEN)
+            })),
+        ]
+    );
+}
+
+#[test]
+fn success_skip_zero_portion() {
+    let default_msg = instantiate::default_msg();
+    let mut deps = mock_deps_with_balance(&coins(1, TEST_INPUT_TOKEN));
+    instantiate::default(&mut deps);
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(vec![
+            (TEST_USER_1.to_string(), Decimal::percent(99)),
+            (TEST_USER_2.to_string(), Decimal::percent(1)),
+        ]),
+        None,
+    )
+    .unwrap();
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(1, TEST_INPUT_TOKEN)),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    let resp = super::swap_earn::exec(&mut deps, env, mock_info(TEST_BENEFICIARY, &[])).unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_USER_2.to_string(),
+            amount: coins(1, TEST_INPUT_TOKEN)
+        }))]
+    );
+}
+
+#[test]
+fn fail_invalid_shares() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        None,
+        Some(vec![
+            (TEST_USER_1.to_string(), Decimal::percent(50)),
+            (TEST_USER_2.to_string(), Decimal::percent(40)),
+        ]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidBeneficiaryShares { sum }) => {
+            assert_eq!(sum, Decimal::percent(90))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        None,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => {
+            assert_eq!(action, "update_beneficiaries")
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
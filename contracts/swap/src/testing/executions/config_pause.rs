@@ -0,0 +1,216 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, coins, from_binary};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, QueryMsg, Role};
+use pylon_gateway::swap_resp_v2::{PauseEventResponse, PauseHistoryResponse, PauseResponse};
+
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_BENEFICIARY, TEST_OWNER, TEST_USER_1};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    sender: &str,
+    deposit: Option<bool>,
+    withdraw: Option<bool>,
+    claim: Option<bool>,
+    earn: Option<bool>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::Pause {
+            deposit,
+            withdraw,
+            claim,
+            earn,
+        })),
+    )
+}
+
+fn deposit(deps: &mut MockDeps) -> ExecuteResult {
+    let default_msg = instantiate::default_msg();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100, default_msg.input_token)),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: None,
+        },
+    )
+}
+
+fn history_msg(start_after: Option<u64>, limit: Option<u32>) -> QueryMsg {
+    QueryMsg::PauseHistory { start_after, limit }
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let resp = exec(&mut deps, TEST_OWNER, Some(true), None, None, Some(true)).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "update_pause"),
+            attr("deposit", "paused"),
+            attr("earn", "paused"),
+        ]
+    );
+
+    let pause: PauseResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pause {}).unwrap()).unwrap();
+    assert_eq!(
+        pause,
+        PauseResponse {
+            deposit: true,
+            withdraw: false,
+            claim: false,
+            earn: true,
+        }
+    );
+
+    match deposit(&mut deps) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "deposit"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_BENEFICIARY, &[]),
+        ExecuteMsg::Earn { amount: None },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "earn"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    // unchanged flags are not logged
+    let resp = exec(&mut deps, TEST_OWNER, Some(false), None, None, Some(true)).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![attr("action", "update_pause"), attr("deposit", "unpaused")]
+    );
+    deposit(&mut deps).unwrap();
+
+    let now = mock_env().block.time.seconds();
+    let history: PauseHistoryResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), history_msg(None, None)).unwrap()).unwrap();
+    assert_eq!(
+        history.events,
+        vec![
+            PauseEventResponse {
+                id: 0,
+                action: "deposit".to_string(),
+                paused: true,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+            PauseEventResponse {
+                id: 1,
+                action: "earn".to_string(),
+                paused: true,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+            PauseEventResponse {
+                id: 2,
+                action: "deposit".to_string(),
+                paused: false,
+                sender: TEST_OWNER.to_string(),
+                time: now,
+            },
+        ]
+    );
+    let history: PauseHistoryResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), history_msg(Some(0), Some(1))).unwrap())
+            .unwrap();
+    assert_eq!(
+        history.events.iter().map(|x| x.id).collect::<Vec<_>>(),
+        vec![1]
+    );
+}
+
+#[test]
+fn success_guardian() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::AssignRole {
+            role: Role::Guardian,
+            address: Some(TEST_USER_1.to_string()),
+        })),
+    )
+    .unwrap();
+
+    exec(&mut deps, TEST_USER_1, None, Some(true), Some(true), None).unwrap();
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        ExecuteMsg::Claim {},
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "claim"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_paused_refund_and_liquidity() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    exec(&mut deps, TEST_OWNER, None, Some(true), None, Some(true)).unwrap();
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        ExecuteMsg::Refund {},
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "withdraw"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::ProvideLiquidity {},
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "earn"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(&mut deps, TEST_USER_1, Some(true), None, None, None) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "update_pause".to_string(),
+                TEST_OWNER.to_string(),
+                TEST_USER_1.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,226 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, to_binary, Api, BankMsg, CosmosMsg, Decimal, Env, MessageInfo, SubMsg, Timestamp,
+    Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use pylon_gateway::swap_msg::ExecuteMsg;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::config::Config;
+use crate::states::state::State;
+use crate::testing::{
+    instantiate, mock_deps, mock_deps_with_balance, MockDeps, TEST_BENEFICIARY, TEST_INPUT_TOKEN,
+    TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_PROTOCOL_ADMIN, TEST_USER_1, TEST_USER_2,
+};
+
+const TEST_FEE_COLLECTOR: &str = TEST_USER_2;
+const SWAP_IN_AMOUNT: u128 = 100;
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    admin: Option<String>,
+    fee_rate: Option<Decimal>,
+    fee_collector: Option<String>,
+    fee_on_claim: Option<bool>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::ConfigureProtocol {
+            admin,
+            fee_rate,
+            fee_collector,
+            fee_on_claim,
+        },
+    )
+}
+
+fn init_with_fee(deps: &mut MockDeps, fee_on_claim: bool) {
+    let mut default_msg = instantiate::default_msg();
+    default_msg.protocol_admin = Some(TEST_PROTOCOL_ADMIN.to_string());
+    instantiate::exec(deps, mock_env(), mock_info(TEST_OWNER, &[]), default_msg).unwrap();
+
+    exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_PROTOCOL_ADMIN, &[]),
+        None,
+        Some(Decimal::percent(10)),
+        Some(TEST_FEE_COLLECTOR.to_string()),
+        Some(fee_on_claim),
+    )
+    .unwrap();
+
+    super::swap_deposit::exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN)),
+    )
+    .unwrap();
+}
+
+fn env_after_lock_period() -> Env {
+    let default_msg = instantiate::default_msg();
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    env
+}
+
+#[test]
+fn success_fee_on_earn() {
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    init_with_fee(&mut deps, false);
+
+    let config = Config::load(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        (
+            config.protocol_admin,
+            config.protocol_fee_rate,
+            config.protocol_fee_collector,
+            config.protocol_fee_on_claim
+        ),
+        (
+            Some(deps.api.addr_validate(TEST_PROTOCOL_ADMIN).unwrap()),
+            Decimal::percent(10),
+            Some(deps.api.addr_validate(TEST_FEE_COLLECTOR).unwrap()),
+            false
+        )
+    );
+
+    let resp = super::swap_earn::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_BENEFICIARY, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: TEST_FEE_COLLECTOR.to_string(),
+                amount: coins(10, TEST_INPUT_TOKEN)
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: TEST_BENEFICIARY.to_string(),
+                amount: coins(90, TEST_INPUT_TOKEN)
+            })),
+        ]
+    );
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "earn"),
+            attr("sender", TEST_BENEFICIARY.to_string()),
+            attr("amount", SWAP_IN_AMOUNT.to_string()),
+            attr("protocol_fee", "10"),
+            attr("total_protocol_fee_input", "10"),
+            attr("total_protocol_fee_output", "0"),
+        ]
+    );
+}
+
+#[test]
+fn success_fee_on_claim() {
+    let mut deps = mock_deps();
+    init_with_fee(&mut deps, true);
+
+    let resp = super::swap_claim::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_1, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_OUTPUT_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: TEST_USER_1.to_string(),
+                    amount: Uint128::from(900u128)
+                })
+                .unwrap(),
+                funds: vec![]
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_OUTPUT_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: TEST_FEE_COLLECTOR.to_string(),
+                    amount: Uint128::from(100u128)
+                })
+                .unwrap(),
+                funds: vec![]
+            })),
+        ]
+    );
+
+    let state = State::load(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        (
+            state.total_protocol_fee_input,
+            state.total_protocol_fee_output
+        ),
+        (Uint128::zero(), Uint128::from(100u128))
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    init_with_fee(&mut deps, false);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        None,
+        Some(Decimal::zero()),
+        None,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "update_protocol".to_string(),
+                TEST_PROTOCOL_ADMIN.to_string(),
+                TEST_OWNER.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_invalid_protocol_fee() {
+    let mut deps = mock_deps();
+    init_with_fee(&mut deps, false);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_PROTOCOL_ADMIN, &[]),
+        None,
+        Some(Decimal::percent(101)),
+        None,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidProtocolFee { rate }) => {
+            assert_eq!(rate, Decimal::percent(101))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,283 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, from_binary, Api, Decimal};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, QueryMsg, Role};
+use pylon_gateway::swap_resp_v2::RolesResponse;
+
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
+
+fn configure(deps: &mut MockDeps, sender: &str, msg: ConfigureMsg) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Configure(Box::new(msg)),
+    )
+}
+
+fn accept_owner(deps: &mut MockDeps, sender: &str) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &[]),
+        ExecuteMsg::AcceptOwner {},
+    )
+}
+
+fn query_roles(deps: &MockDeps) -> RolesResponse {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Roles {}).unwrap()).unwrap()
+}
+
+fn whitelist(candidate: &str) -> ConfigureMsg {
+    ConfigureMsg::Whitelist {
+        whitelist: true,
+        candidates: vec![candidate.to_string()],
+    }
+}
+
+fn deposit_tier() -> ConfigureMsg {
+    ConfigureMsg::DepositTier { tier: None }
+}
+
+#[test]
+fn success_transfer_ownership() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let resp = configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_USER_1.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![attr("action", "propose_owner"), attr("owner", TEST_USER_1)]
+    );
+
+    // ownership stays until accepted
+    configure(&mut deps, TEST_OWNER, deposit_tier()).unwrap();
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::WhitelistManager,
+            address: Some(TEST_USER_2.to_string()),
+        },
+    )
+    .unwrap();
+
+    let resp = accept_owner(&mut deps, TEST_USER_1).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "accept_owner"),
+            attr("owner", TEST_USER_1),
+            attr("previous", TEST_OWNER),
+        ]
+    );
+    assert_eq!(
+        query_roles(&deps),
+        RolesResponse {
+            owner: TEST_USER_1.to_string(),
+            pending_owner: None,
+            config_admin: None,
+            whitelist_manager: None,
+            reward_manager: None,
+            guardian: None,
+        }
+    );
+
+    match configure(&mut deps, TEST_OWNER, deposit_tier()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn success_cancel_owner_proposal() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_USER_1.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        query_roles(&deps).pending_owner,
+        Some(TEST_USER_1.to_string())
+    );
+
+    configure(&mut deps, TEST_OWNER, ConfigureMsg::CancelOwnerProposal {}).unwrap();
+    assert_eq!(query_roles(&deps).pending_owner, None);
+
+    match accept_owner(&mut deps, TEST_USER_1) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "accept_owner".to_string(),
+                "<not assigned>".to_string(),
+                TEST_USER_1.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn success_assign_role() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let resp = configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::WhitelistManager,
+            address: Some(TEST_USER_1.to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "assign_role"),
+            attr("role", "whitelist_manager"),
+            attr("address", TEST_USER_1),
+        ]
+    );
+    assert_eq!(
+        query_roles(&deps).whitelist_manager,
+        Some(TEST_USER_1.to_string())
+    );
+
+    // whitelist manager is limited to whitelist and tier assignments
+    configure(&mut deps, TEST_USER_1, whitelist(TEST_USER_2)).unwrap();
+    let api = deps.api;
+    assert!(User::is_whitelisted(
+        deps.as_ref().storage,
+        &api.addr_canonicalize(TEST_USER_2).unwrap()
+    ));
+    match configure(&mut deps, TEST_USER_1, deposit_tier()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::WhitelistManager,
+            address: None,
+        },
+    )
+    .unwrap();
+    match configure(&mut deps, TEST_USER_1, whitelist(TEST_USER_2)) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    configure(
+        &mut deps,
+        TEST_OWNER,
+        ConfigureMsg::AssignRole {
+            role: Role::ConfigAdmin,
+            address: Some(TEST_USER_1.to_string()),
+        },
+    )
+    .unwrap();
+    configure(&mut deps, TEST_USER_1, deposit_tier()).unwrap();
+
+    // config admin cannot route funds
+    for msg in [
+        ConfigureMsg::Config {
+            beneficiary: Some(TEST_USER_1.to_string()),
+            input_token: None,
+            output_token: None,
+            deposit_cap_strategy: None,
+            distribution_strategies: None,
+            whitelist_enabled: None,
+            receipt_token: None,
+            earn_by_claim: None,
+        },
+        ConfigureMsg::Beneficiaries {
+            proceeds: Some(vec![(TEST_USER_1.to_string(), Decimal::one())]),
+            penalty: None,
+        },
+        ConfigureMsg::DepositRoute {
+            asset: "ukrw".to_string(),
+            pair: Some(TEST_USER_1.to_string()),
+        },
+        ConfigureMsg::LiquiditySeed {
+            liquidity_seed: None,
+        },
+    ]
+    .iter()
+    {
+        match configure(&mut deps, TEST_USER_1, msg.clone()) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::Unauthorized { .. }) => (),
+            Err(e) => panic!("Unexpected error {:?}", e),
+        }
+    }
+
+    // role holders cannot manage ownership or roles
+    match configure(
+        &mut deps,
+        TEST_USER_1,
+        ConfigureMsg::ProposeOwner {
+            owner: TEST_USER_1.to_string(),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized {
+            action,
+            expected,
+            actual,
+        }) => assert_eq!(
+            (action, expected, actual),
+            (
+                "propose_owner".to_string(),
+                TEST_OWNER.to_string(),
+                TEST_USER_1.to_string()
+            )
+        ),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+    match configure(
+        &mut deps,
+        TEST_USER_1,
+        ConfigureMsg::AssignRole {
+            role: Role::WhitelistManager,
+            address: Some(TEST_USER_1.to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { .. }) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{attr, Api, Env, MessageInfo};
+use cosmwasm_std::{attr, Api, Decimal, Env, MessageInfo};
 use cw20::Denom;
 use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg};
 use pylon_gateway::swap_types;
@@ -8,7 +8,9 @@ use crate::entrypoints::execute;
 use crate::error::ContractError;
 use crate::executions::ExecuteResult;
 use crate::states::config::Config;
-use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_USER_1, TEST_USER_2,
+};
 use crate::types::cap_strategy::CapStrategy;
 
 #[allow(clippy::too_many_arguments)]
@@ -16,26 +18,28 @@ pub fn exec(
     deps: &mut MockDeps,
     env: Env,
     info: MessageInfo,
-    owner: Option<String>,
     beneficiary: Option<String>,
     input_token: Option<String>,
     output_token: Option<String>,
     deposit_cap_strategy: Option<swap_types::CapStrategy>,
     distribution_strategies: Option<Vec<swap_types::DistributionStrategy>>,
     whitelist_enabled: Option<bool>,
+    receipt_token: Option<String>,
+    earn_by_claim: Option<bool>,
 ) -> ExecuteResult {
     execute(
         deps.as_mut(),
         env,
         info,
         ExecuteMsg::Configure(Box::new(ConfigureMsg::Config {
-            owner,
             beneficiary,
             input_token,
             output_token,
             deposit_cap_strategy,
             distribution_strategies,
             whitelist_enabled,
+            receipt_token,
+            earn_by_claim,
         })),
     )
 }
@@ -49,7 +53,6 @@ fn success() {
         &mut deps,
         mock_env(),
         mock_info(TEST_OWNER, &[]),
-        Some(TEST_USER_1.to_string()),
         Some(TEST_USER_2.to_string()),
         Some("ukrw".to_string()),
         Some(TEST_OWNER.to_string()),
@@ -59,6 +62,8 @@ fn success() {
         }),
         Some(vec![]),
         Some(true),
+        Some(TEST_OUTPUT_TOKEN.to_string()),
+        Some(true),
     )
     .unwrap();
     assert_eq!(resp.attributes, vec![attr("action", "update_config")]);
@@ -68,8 +73,10 @@ fn success() {
     assert_eq!(
         Config::load(deps.as_ref().storage).unwrap(),
         Config {
-            owner: api.addr_validate(TEST_USER_1).unwrap(),
+            owner: api.addr_validate(TEST_OWNER).unwrap(),
             beneficiary: api.addr_validate(TEST_USER_2).unwrap(),
+            proceeds_beneficiaries: vec![],
+            penalty_beneficiaries: vec![],
             start: default_msg.start,
             finish: default_msg.start + default_msg.period,
             price: default_msg.price,
@@ -81,7 +88,19 @@ fn success() {
                 max_user_cap: None
             }),
             distribution_strategies: vec![],
-            whitelist_enabled: true
+            tier_distribution_strategies: vec![],
+            deposit_tier: None,
+            whitelist_enabled: true,
+            receipt_token: Some(api.addr_validate(TEST_OUTPUT_TOKEN).unwrap()),
+            soft_cap: None,
+            earn_by_claim: true,
+            protocol_admin: None,
+            protocol_fee_rate: Decimal::zero(),
+            protocol_fee_collector: None,
+            protocol_fee_on_claim: false,
+            liquidity_seed: None,
+            deposit_routes: vec![],
+            referral_reward: None
         }
     );
 }
@@ -102,6 +121,7 @@ fn fail_unauthorized() {
         None,
         None,
         None,
+        None,
     ) {
         Ok(_) => panic!("Must return error"),
         Err(ContractError::Unauthorized {
@@ -121,3 +141,32 @@ fn fail_unauthorized() {
         Err(e) => panic!("Unexpected error {:?}", e),
     }
 }
+
+#[test]
+fn fail_invalid_distribution_strategies() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        None,
+        None,
+        None,
+        None,
+        Some(vec![swap_types::DistributionStrategy::Lockup {
+            release_time: 0,
+            release_amount: Decimal::percent(50),
+        }]),
+        None,
+        None,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InvalidReleaseAmountSum { sum }) => {
+            assert_eq!(sum, Decimal::percent(50))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,324 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, from_binary, to_binary, BankMsg, CosmosMsg, Decimal, Env, MessageInfo, SubMsg,
+    Timestamp, Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg};
+use pylon_gateway::swap_types;
+use terraswap::asset::{Asset, AssetInfo};
+use terraswap::pair::ExecuteMsg as PairExecuteMsg;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::state::State;
+use crate::testing::{
+    instantiate, mock_deps, mock_deps_with_balance, MockDeps, TEST_BENEFICIARY, TEST_INPUT_TOKEN,
+    TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_USER_1, TEST_USER_2,
+};
+
+pub const TEST_PAIR: &str = "terra1m6ywlgn6wrjuagcmmezzz2a029gtldhey5k552";
+const SWAP_IN_AMOUNT: u128 = 100;
+const SWAPPED_OUT_AMOUNT: u128 = 1000;
+const SEED_OUTPUT_AMOUNT: u128 = 500;
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::ProvideLiquidity {})
+}
+
+fn register_output_balance(deps: &mut MockDeps, amount: u128) {
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_OUTPUT_TOKEN.to_string(),
+        Box::new(move |_| {
+            to_binary(&BalanceResponse {
+                balance: Uint128::from(amount),
+            })
+        }),
+    );
+}
+
+pub fn configure_seed(deps: &mut MockDeps, lp_recipient: Option<String>) {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::LiquiditySeed {
+            liquidity_seed: Some(swap_types::LiquiditySeed {
+                pair: TEST_PAIR.to_string(),
+                input_ratio: Decimal::percent(50),
+                output_amount: Uint128::from(SEED_OUTPUT_AMOUNT),
+                lp_recipient,
+            }),
+        })),
+    )
+    .unwrap();
+}
+
+pub fn init_with_seed(deps: &mut MockDeps) {
+    instantiate::default(deps);
+    register_output_balance(deps, SWAPPED_OUT_AMOUNT + SEED_OUTPUT_AMOUNT);
+    configure_seed(deps, Some(TEST_OWNER.to_string()));
+
+    super::swap_deposit::exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN)),
+    )
+    .unwrap();
+}
+
+pub fn env_after_lock_period() -> Env {
+    let default_msg = instantiate::default_msg();
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    env
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    init_with_seed(&mut deps);
+
+    let resp = exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_OUTPUT_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: TEST_PAIR.to_string(),
+                    amount: Uint128::from(SEED_OUTPUT_AMOUNT),
+                    expires: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_PAIR.to_string(),
+                msg: to_binary(&PairExecuteMsg::ProvideLiquidity {
+                    assets: [
+                        Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: TEST_INPUT_TOKEN.to_string()
+                            },
+                            amount: Uint128::from(50u128),
+                        },
+                        Asset {
+                            info: AssetInfo::Token {
+                                contract_addr: TEST_OUTPUT_TOKEN.to_string()
+                            },
+                            amount: Uint128::from(SEED_OUTPUT_AMOUNT),
+                        },
+                    ],
+                    slippage_tolerance: None,
+                    receiver: Some(TEST_OWNER.to_string()),
+                })
+                .unwrap(),
+                funds: coins(50, TEST_INPUT_TOKEN),
+            })),
+        ]
+    );
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "provide_liquidity"),
+            attr("sender", TEST_OWNER.to_string()),
+            attr("input_amount", "50"),
+            attr("output_amount", SEED_OUTPUT_AMOUNT.to_string()),
+        ]
+    );
+
+    let state = State::load(deps.as_ref().storage).unwrap();
+    assert!(state.liquidity_seeded);
+    assert_eq!(state.liquidity_seed_input, Uint128::from(50u128));
+
+    // rest goes to beneficiary
+    let resp = super::swap_earn::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_BENEFICIARY, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_BENEFICIARY.to_string(),
+            amount: coins(50, TEST_INPUT_TOKEN),
+        }))]
+    );
+}
+
+#[test]
+fn fail_not_allow_earn_before_liquidity() {
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    init_with_seed(&mut deps);
+
+    match super::swap_earn::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_BENEFICIARY, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowEarnBeforeLiquidity {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_liquidity_already_provided() {
+    let mut deps = mock_deps();
+    init_with_seed(&mut deps);
+
+    exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    )
+    .unwrap();
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::LiquidityAlreadyProvided {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_liquidity_seed_not_configured() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::LiquiditySeedNotConfigured {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_insufficient_liquidity_seed_balance() {
+    let mut deps = mock_deps();
+    init_with_seed(&mut deps);
+    register_output_balance(&mut deps, SWAPPED_OUT_AMOUNT + SEED_OUTPUT_AMOUNT - 1);
+
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InsufficientLiquiditySeedBalance { available }) => {
+            assert_eq!(available, Uint128::from(SEED_OUTPUT_AMOUNT - 1))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_insufficient_liquidity_seed_balance_with_referral_reward() {
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    instantiate::default(&mut deps);
+    register_output_balance(&mut deps, SWAPPED_OUT_AMOUNT + SEED_OUTPUT_AMOUNT);
+    configure_seed(&mut deps, Some(TEST_OWNER.to_string()));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::ReferralReward {
+            reward: Some(swap_types::ReferralReward::Output {
+                rate: Decimal::percent(10),
+            }),
+        })),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN)),
+        ExecuteMsg::Deposit {
+            referrer: Some(TEST_USER_2.to_string()),
+            recipient: None,
+        },
+    )
+    .unwrap();
+
+    // unclaimed referral reward in output token stays for the referrer
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::InsufficientLiquiditySeedBalance { available }) => {
+            assert_eq!(available, Uint128::from(SEED_OUTPUT_AMOUNT - 100))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    super::referral_claim::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_2, &[]),
+    )
+    .unwrap();
+    exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    )
+    .unwrap();
+}
+
+#[test]
+fn success_lock_lp_tokens() {
+    let mut deps = mock_deps_with_balance(&coins(SWAP_IN_AMOUNT, TEST_INPUT_TOKEN));
+    init_with_seed(&mut deps);
+    configure_seed(&mut deps, None);
+
+    let resp = exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    )
+    .unwrap();
+    match &resp.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_binary(msg).unwrap() {
+            PairExecuteMsg::ProvideLiquidity { receiver, .. } => assert_eq!(receiver, None),
+            _ => panic!("Unexpected message"),
+        },
+        _ => panic!("Unexpected message"),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    init_with_seed(&mut deps);
+
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_1, &[]),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => {
+            assert_eq!(action, "provide_liquidity")
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,148 @@
+use cosmwasm_std::testing::mock_info;
+use cosmwasm_std::{attr, coins, to_binary, CosmosMsg, Env, MessageInfo, SubMsg, Uint128, WasmMsg};
+use cw20::{BalanceResponse, Cw20ExecuteMsg};
+use pylon_gateway::swap_msg::ExecuteMsg;
+use terraswap::asset::{AssetInfo, PairInfo};
+
+use super::liquidity_provide::{env_after_lock_period, init_with_seed, TEST_PAIR};
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::testing::{
+    mock_deps_with_balance, MockDeps, TEST_INPUT_TOKEN, TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_USER_1,
+};
+
+const TEST_LP_TOKEN: &str = "terra1pkrw5a47fjx2e2teypftzpwnpfhfhn3gyke6mv";
+const LP_AMOUNT: u128 = 300;
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    recipient: &str,
+    amount: Option<Uint128>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::ReleaseLiquidity {
+            recipient: recipient.to_string(),
+            amount,
+        },
+    )
+}
+
+fn init_with_locked_lp_tokens(deps: &mut MockDeps) {
+    init_with_seed(deps);
+    super::liquidity_provide::configure_seed(deps, None);
+
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_PAIR.to_string(),
+        Box::new(|_| {
+            to_binary(&PairInfo {
+                asset_infos: [
+                    AssetInfo::NativeToken {
+                        denom: TEST_INPUT_TOKEN.to_string(),
+                    },
+                    AssetInfo::Token {
+                        contract_addr: TEST_OUTPUT_TOKEN.to_string(),
+                    },
+                ],
+                contract_addr: TEST_PAIR.to_string(),
+                liquidity_token: TEST_LP_TOKEN.to_string(),
+            })
+        }),
+    );
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_LP_TOKEN.to_string(),
+        Box::new(|_| {
+            to_binary(&BalanceResponse {
+                balance: Uint128::from(LP_AMOUNT),
+            })
+        }),
+    );
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps_with_balance(&coins(100u128, TEST_INPUT_TOKEN));
+    init_with_locked_lp_tokens(&mut deps);
+    super::liquidity_provide::exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+    )
+    .unwrap();
+
+    // releases all locked lp tokens by default
+    for (amount, expected) in [(None, LP_AMOUNT), (Some(Uint128::from(100u128)), 100u128)].iter() {
+        let resp = exec(
+            &mut deps,
+            env_after_lock_period(),
+            mock_info(TEST_OWNER, &[]),
+            TEST_USER_1,
+            *amount,
+        )
+        .unwrap();
+        assert_eq!(
+            resp.messages,
+            vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_LP_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: TEST_USER_1.to_string(),
+                    amount: Uint128::from(*expected),
+                })
+                .unwrap(),
+                funds: vec![],
+            }))]
+        );
+        assert_eq!(
+            resp.attributes,
+            vec![
+                attr("action", "release_liquidity"),
+                attr("sender", TEST_OWNER.to_string()),
+                attr("recipient", TEST_USER_1.to_string()),
+                attr("amount", expected.to_string()),
+            ]
+        );
+    }
+}
+
+#[test]
+fn fail_liquidity_not_provided() {
+    let mut deps = mock_deps_with_balance(&coins(100u128, TEST_INPUT_TOKEN));
+    init_with_locked_lp_tokens(&mut deps);
+
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_OWNER, &[]),
+        TEST_USER_1,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::LiquidityNotProvided {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps_with_balance(&coins(100u128, TEST_INPUT_TOKEN));
+    init_with_locked_lp_tokens(&mut deps);
+
+    match exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_1, &[]),
+        TEST_USER_1,
+        None,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => {
+            assert_eq!(action, "release_liquidity")
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -1,7 +1,20 @@
+mod config_beneficiaries;
+mod config_pause;
+mod config_protocol;
+mod config_role;
 mod config_update;
+mod liquidity_provide;
+mod liquidity_release;
+mod referral_claim;
 mod state_update;
 mod swap_claim;
 mod swap_deposit;
+mod swap_deposit_routed;
 mod swap_earn;
+mod swap_refund;
+mod swap_transfer;
 mod swap_withdraw;
+mod user_register;
+mod user_snapshot;
+mod user_tier;
 mod user_whitelist;
@@ -0,0 +1,321 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, from_binary, to_binary, BankMsg, CosmosMsg, Decimal, Env, MessageInfo, SubMsg,
+    Timestamp, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, QueryMsg};
+use pylon_gateway::swap_resp_v2::{ReferrerResponse, TopReferrersResponse};
+use pylon_gateway::swap_types;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::state::State;
+use crate::testing::{
+    instantiate, mock_deps, MockDeps, TEST_GOV, TEST_INPUT_TOKEN, TEST_OUTPUT_TOKEN, TEST_OWNER,
+    TEST_USER_1, TEST_USER_2,
+};
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::ClaimReferralReward {})
+}
+
+fn deposit(deps: &mut MockDeps, sender: &str, amount: u128, referrer: &str) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &coins(amount, TEST_INPUT_TOKEN)),
+        ExecuteMsg::Deposit {
+            referrer: Some(referrer.to_string()),
+            recipient: None,
+        },
+    )
+}
+
+fn init_with_reward(deps: &mut MockDeps, reward: swap_types::ReferralReward) {
+    instantiate::default(deps);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::ReferralReward {
+            reward: Some(reward),
+        })),
+    )
+    .unwrap();
+}
+
+fn env_after_lock_period() -> Env {
+    let default_msg = instantiate::default_msg();
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    env
+}
+
+#[test]
+fn success_input_reward() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+
+    let resp = deposit(&mut deps, TEST_USER_1, 100, TEST_USER_2).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "deposit"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("swapped_in", "100"),
+            attr("swapped_out", "1000"),
+            attr("referrer", TEST_USER_2.to_string()),
+        ]
+    );
+    deposit(&mut deps, TEST_OWNER, 200, TEST_GOV).unwrap();
+
+    let top: TopReferrersResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::TopReferrers { limit: None },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        top.referrers
+            .iter()
+            .map(|x| (x.address.as_str(), x.referred_in.u128()))
+            .collect::<Vec<_>>(),
+        vec![(TEST_GOV, 200), (TEST_USER_2, 100)]
+    );
+
+    let resp = exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_2, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_USER_2.to_string(),
+            amount: coins(10, TEST_INPUT_TOKEN),
+        }))]
+    );
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "claim_referral_reward"),
+            attr("sender", TEST_USER_2.to_string()),
+            attr("amount_in", "10"),
+            attr("amount_out", "0"),
+        ]
+    );
+
+    let referrer: ReferrerResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Referrer {
+                address: TEST_USER_2.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        referrer,
+        ReferrerResponse {
+            address: TEST_USER_2.to_string(),
+            referred_in: Uint128::from(100u128),
+            referred_count: 1,
+            reward_in: Uint128::from(10u128),
+            reward_in_claimed: Uint128::from(10u128),
+            reward_out: Uint128::zero(),
+            reward_out_claimed: Uint128::zero(),
+        }
+    );
+}
+
+#[test]
+fn success_output_reward() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Output {
+            rate: Decimal::percent(10),
+        },
+    );
+    deposit(&mut deps, TEST_USER_1, 100, TEST_USER_2).unwrap();
+
+    let resp = exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_2, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: TEST_OUTPUT_TOKEN.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: TEST_USER_2.to_string(),
+                amount: Uint128::from(100u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+}
+
+#[test]
+fn success_reverse_on_withdraw() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+    deposit(&mut deps, TEST_USER_1, 100, TEST_USER_2).unwrap();
+
+    // half of the swapped amount goes back
+    super::swap_withdraw::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        Uint128::from(500u128),
+    )
+    .unwrap();
+
+    let referrer: ReferrerResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Referrer {
+                address: TEST_USER_2.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(referrer.referred_in, Uint128::from(50u128));
+    assert_eq!(referrer.reward_in, Uint128::from(5u128));
+    let state = State::load(deps.as_ref().storage).unwrap();
+    assert_eq!(state.total_referral_reward_in, Uint128::from(5u128));
+
+    let resp = exec(
+        &mut deps,
+        env_after_lock_period(),
+        mock_info(TEST_USER_2, &[]),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_USER_2.to_string(),
+            amount: coins(5, TEST_INPUT_TOKEN),
+        }))]
+    );
+}
+
+#[test]
+fn fail_not_allow_self_referral() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+
+    match deposit(&mut deps, TEST_USER_1, 100, TEST_USER_1) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowSelfReferral {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn success_withdraw_after_transfer_all() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+    super::swap_transfer::configure_receipt_token(&mut deps);
+    deposit(&mut deps, TEST_USER_1, 100, TEST_USER_2).unwrap();
+
+    // the whole position moves away, referrals stay with the depositor
+    super::swap_transfer::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(super::swap_transfer::TEST_RECEIPT_TOKEN, &[]),
+        TEST_USER_1,
+        TEST_OWNER,
+        1000,
+    )
+    .unwrap();
+
+    super::swap_withdraw::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        Uint128::zero(),
+    )
+    .unwrap();
+
+    let state = State::load(deps.as_ref().storage).unwrap();
+    assert_eq!(state.total_referral_reward_in, Uint128::from(10u128));
+}
+
+#[test]
+fn fail_not_allow_self_referral_by_sender() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100, TEST_INPUT_TOKEN)),
+        ExecuteMsg::Deposit {
+            referrer: Some(TEST_USER_1.to_string()),
+            recipient: Some(TEST_USER_2.to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowSelfReferral {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_not_allow_claim_before_lock_period() {
+    let mut deps = mock_deps();
+    init_with_reward(
+        &mut deps,
+        swap_types::ReferralReward::Input {
+            rate: Decimal::percent(10),
+        },
+    );
+    deposit(&mut deps, TEST_USER_1, 100, TEST_USER_2).unwrap();
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_USER_2, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowReferralClaimBeforeLockPeriod {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
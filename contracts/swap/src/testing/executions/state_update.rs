@@ -47,7 +47,18 @@ fn success() {
             total_swapped: Default::default(),
             total_claimed: Default::default(),
             x_liquidity: Uint128::from(1234u128),
-            y_liquidity: Uint128::from(4321u128)
+            y_liquidity: Uint128::from(4321u128),
+            cancelled: false,
+            total_raised: Uint128::zero(),
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero()
         }
     );
 }
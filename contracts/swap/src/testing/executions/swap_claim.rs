@@ -8,11 +8,14 @@ use pylon_gateway::swap_msg::ExecuteMsg;
 use pylon_gateway::swap_types;
 
 use crate::entrypoints::execute;
+use crate::error::ContractError;
 use crate::executions::ExecuteResult;
+use crate::states::config::Config;
 use crate::states::user::User;
 use crate::testing::{
     instantiate, mock_deps, MockDeps, TEST_OUTPUT_TOKEN, TEST_OWNER, TEST_USER_1,
 };
+use crate::types::distribution_strategy::DistributionStrategy;
 
 pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
     execute(deps.as_mut(), env, info, ExecuteMsg::Claim {})
@@ -42,7 +45,8 @@ pub fn assert_claim_response(
         vec![
             attr("action", "claim"),
             attr("sender", user.to_string()),
-            attr("amount", amount.to_string()) // 100%
+            attr("amount", amount.to_string()), // 100%
+            attr("protocol_fee", "0"),
         ]
     );
     assert_eq!(
@@ -172,3 +176,124 @@ fn success_vesting_strategy() {
     let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
     assert_claim_response(&deps, resp, TEST_USER_1, 3400u128, 10000u128);
 }
+
+#[test]
+fn success_periodic_strategy() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    default_msg.distribution_strategies = vec![
+        swap_types::DistributionStrategy::Lockup {
+            release_time: default_msg.start + 10,
+            release_amount: Decimal::percent(10),
+        },
+        swap_types::DistributionStrategy::Periodic {
+            start: default_msg.start + 10,
+            interval: 30,
+            count: 3,
+            amount_per_period: Decimal::percent(30),
+        },
+    ];
+
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg.clone(),
+    )
+    .unwrap();
+
+    const SWAP_IN_AMOUNT: u128 = 1000;
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, default_msg.input_token)),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 10);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_claim_response(&deps, resp, TEST_USER_1, 4000u128, 4000u128);
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 40);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_claim_response(&deps, resp, TEST_USER_1, 3000u128, 7000u128);
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 100);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_claim_response(&deps, resp, TEST_USER_1, 3000u128, 10000u128);
+}
+
+#[test]
+fn success_cliff_vesting_strategy() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    default_msg.distribution_strategies = vec![swap_types::DistributionStrategy::CliffVesting {
+        cliff: default_msg.start + 50,
+        start: default_msg.start,
+        finish: default_msg.start + default_msg.period,
+        amount: Decimal::one(),
+    }];
+
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg.clone(),
+    )
+    .unwrap();
+
+    const SWAP_IN_AMOUNT: u128 = 1000;
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, default_msg.input_token)),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 50);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_claim_response(&deps, resp, TEST_USER_1, 5000u128, 5000u128);
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + default_msg.period + 1);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_claim_response(&deps, resp, TEST_USER_1, 5000u128, 10000u128);
+}
+
+#[test]
+fn fail_release_amount_overflow() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    // stored before strategies were validated
+    let amount_per_period = Decimal::from_ratio(200_000_000_000_000_000_000u128, 1u128);
+    let mut config = Config::load(deps.as_ref().storage).unwrap();
+    config.distribution_strategies = vec![DistributionStrategy::Periodic {
+        start: default_msg.start,
+        interval: 1,
+        count: 2,
+        amount_per_period,
+    }];
+    Config::save(deps.as_mut().storage, &config).unwrap();
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 1);
+    match exec(&mut deps, env, mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::ReleaseAmountOverflow {
+            amount_per_period: overflowed,
+            count,
+        }) => {
+            assert_eq!(overflowed, amount_per_period);
+            assert_eq!(count, 2);
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
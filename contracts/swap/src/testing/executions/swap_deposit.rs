@@ -8,10 +8,18 @@ use crate::error::ContractError;
 use crate::executions::ExecuteResult;
 use crate::states::state::State;
 use crate::states::user::User;
-use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1};
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
 
 pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
-    execute(deps.as_mut(), env, info, ExecuteMsg::Deposit {})
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: None,
+        },
+    )
 }
 
 #[test]
@@ -59,11 +67,68 @@ fn success() {
             total_swapped: Uint128::from(swap_out_amount),
             total_claimed: Uint128::zero(),
             x_liquidity: default_msg.x_liquidity,
-            y_liquidity: default_msg.y_liquidity
+            y_liquidity: default_msg.y_liquidity,
+            cancelled: false,
+            total_raised: Uint128::from(SWAP_IN_AMOUNT),
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero()
         }
     );
 }
 
+#[test]
+fn success_with_recipient() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    let resp = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: Some(TEST_USER_2.to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "deposit"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("swapped_in", "100"),
+            attr("swapped_out", "1000"),
+            attr("recipient", TEST_USER_2.to_string()),
+        ]
+    );
+
+    let api = deps.api;
+    assert_eq!(
+        User::load(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap()
+        )
+        .swapped_in,
+        Uint128::zero()
+    );
+    assert_eq!(
+        User::load(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_2).unwrap()
+        )
+        .swapped_in,
+        Uint128::from(100u128)
+    );
+}
+
 #[test]
 fn fail_swap_not_started() {
     let mut deps = mock_deps();
@@ -172,6 +237,38 @@ fn fail_not_allow_non_whitelisted() {
     }
 }
 
+#[test]
+fn fail_not_allow_non_whitelisted_recipient() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    default_msg.whitelist_enabled = true;
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg.clone(),
+    )
+    .unwrap();
+    let sender = deps.api.addr_canonicalize(TEST_USER_1).unwrap();
+    User::register_whitelist(deps.as_mut().storage, &sender).unwrap();
+
+    match execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: Some(TEST_USER_2.to_string()),
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowNonWhitelisted { address }) => {
+            assert_eq!(address, TEST_USER_2.to_string())
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
 #[test]
 fn fail_available_cap_exceeded() {
     let mut deps = mock_deps();
@@ -200,3 +297,171 @@ fn fail_available_cap_exceeded() {
         Err(e) => panic!("Unexpected error {:?}", e),
     }
 }
+
+#[test]
+fn fail_available_cap_exceeded_composite() {
+    let fixed = |cap: Option<u128>| swap_types::CapStrategy::Fixed {
+        min_user_cap: None,
+        max_user_cap: cap.map(Uint128::from),
+    };
+
+    for (strategy, expected) in [
+        (
+            swap_types::CapStrategy::Min(vec![fixed(Some(50)), fixed(None), fixed(Some(70))]),
+            50u128,
+        ),
+        (
+            swap_types::CapStrategy::Max(vec![fixed(Some(50)), fixed(Some(70))]),
+            70u128,
+        ),
+        (
+            swap_types::CapStrategy::Sum(vec![fixed(Some(30)), fixed(Some(50))]),
+            80u128,
+        ),
+    ] {
+        let mut deps = mock_deps();
+        let mut default_msg = instantiate::default_msg();
+        default_msg.deposit_cap_strategy = Some(strategy);
+        instantiate::exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_OWNER, &[]),
+            default_msg.clone(),
+        )
+        .unwrap();
+
+        match exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::AvailableCapExceeded { available }) => {
+                assert_eq!(available, Uint128::from(expected))
+            }
+            Err(e) => panic!("Unexpected error {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn success_composite_with_unlimited() {
+    let fixed = |cap: Option<u128>| swap_types::CapStrategy::Fixed {
+        min_user_cap: None,
+        max_user_cap: cap.map(Uint128::from),
+    };
+
+    // an unlimited strategy makes max and sum unlimited
+    for strategy in [
+        swap_types::CapStrategy::Max(vec![fixed(Some(50)), fixed(None)]),
+        swap_types::CapStrategy::Sum(vec![fixed(Some(30)), fixed(None)]),
+    ] {
+        let mut deps = mock_deps();
+        let mut default_msg = instantiate::default_msg();
+        default_msg.deposit_cap_strategy = Some(strategy);
+        instantiate::exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_OWNER, &[]),
+            default_msg.clone(),
+        )
+        .unwrap();
+
+        exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn fail_available_cap_exceeded_sum_with_min_user_cap() {
+    let fixed = |min: Option<u128>, max: u128| swap_types::CapStrategy::Fixed {
+        min_user_cap: min.map(Uint128::from),
+        max_user_cap: Some(Uint128::from(max)),
+    };
+
+    // caps of all strategies are summed up even if a strategy has its own minimum
+    for (swapped_in, expected) in [(20u128, 60u128), (10u128, 0u128)] {
+        let mut deps = mock_deps();
+        let mut default_msg = instantiate::default_msg();
+        default_msg.deposit_cap_strategy = Some(swap_types::CapStrategy::Sum(vec![
+            fixed(Some(20), 30),
+            fixed(None, 50),
+        ]));
+        instantiate::exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_OWNER, &[]),
+            default_msg.clone(),
+        )
+        .unwrap();
+
+        let api = deps.api;
+        User::save(
+            deps.as_mut().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap(),
+            &User {
+                swapped_in: Uint128::from(swapped_in),
+                ..User::default()
+            },
+        )
+        .unwrap();
+
+        match exec(
+            &mut deps,
+            mock_env(),
+            mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::AvailableCapExceeded { available }) => {
+                assert_eq!(available, Uint128::from(expected))
+            }
+            Err(e) => panic!("Unexpected error {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn fail_available_cap_exceeded_scheduled() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    let fixed = |cap: u128| swap_types::CapStrategy::Fixed {
+        min_user_cap: None,
+        max_user_cap: Some(Uint128::from(cap)),
+    };
+    default_msg.deposit_cap_strategy = Some(swap_types::CapStrategy::Scheduled {
+        phases: vec![
+            (default_msg.start, fixed(50)),
+            (default_msg.start + 10, fixed(70)),
+        ],
+    });
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg.clone(),
+    )
+    .unwrap();
+
+    for (elapsed, expected) in [(0u64, 50u128), (10u64, 70u128)] {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(default_msg.start + elapsed);
+        match exec(
+            &mut deps,
+            env,
+            mock_info(
+                TEST_USER_1,
+                &coins(100u128, default_msg.input_token.clone()),
+            ),
+        ) {
+            Ok(_) => panic!("Must return error"),
+            Err(ContractError::AvailableCapExceeded { available }) => {
+                assert_eq!(available, Uint128::from(expected))
+            }
+            Err(e) => panic!("Unexpected error {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, coin, coins, to_binary, Api, ContractResult, CosmosMsg, Env, MessageInfo, Reply, SubMsg,
+    SubMsgExecutionResponse, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use pylon_gateway::swap_msg::{ConfigureMsg, Cw20HookMsg, ExecuteMsg};
+use terraswap::asset::{Asset, AssetInfo};
+use terraswap::pair::{Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg};
+
+use crate::constants::DEPOSIT_ROUTE_REPLY_ID;
+use crate::entrypoints::{execute, reply};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_INPUT_TOKEN, TEST_OWNER, TEST_USER_1};
+
+const TEST_OFFER_DENOM: &str = "ukrw";
+const TEST_OFFER_TOKEN: &str = "terra1kc87mu460fwkqte29rquh4hc20m54fxwtsx7gp";
+const TEST_PAIR: &str = "terra1m6ywlgn6wrjuagcmmezzz2a029gtldhey5k552";
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    min_receive: Uint128,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::DepositRouted {
+            min_receive,
+            referrer: None,
+        },
+    )
+}
+
+fn exec_reply(deps: &mut MockDeps, received: u128) -> ExecuteResult {
+    deps.querier.update_balance(
+        MOCK_CONTRACT_ADDR.to_string(),
+        coins(received, TEST_INPUT_TOKEN),
+    );
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: DEPOSIT_ROUTE_REPLY_ID,
+            result: ContractResult::Ok(SubMsgExecutionResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+}
+
+fn init_with_route(deps: &mut MockDeps, asset: &str) {
+    instantiate::default(deps);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::DepositRoute {
+            asset: asset.to_string(),
+            pair: Some(TEST_PAIR.to_string()),
+        })),
+    )
+    .unwrap();
+}
+
+fn assert_swapped_in(deps: &MockDeps, amount: u128) {
+    assert_eq!(
+        User::load(
+            deps.as_ref().storage,
+            &deps.api.addr_canonicalize(TEST_USER_1).unwrap()
+        )
+        .swapped_in,
+        Uint128::from(amount)
+    );
+}
+
+#[test]
+fn success_native() {
+    let mut deps = mock_deps();
+    init_with_route(&mut deps, TEST_OFFER_DENOM);
+
+    let resp = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(1000, TEST_OFFER_DENOM)),
+        Uint128::from(90u128),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_PAIR.to_string(),
+                msg: to_binary(&PairExecuteMsg::Swap {
+                    offer_asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: TEST_OFFER_DENOM.to_string()
+                        },
+                        amount: Uint128::from(1000u128),
+                    },
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                })
+                .unwrap(),
+                funds: vec![coin(1000, TEST_OFFER_DENOM)],
+            }),
+            DEPOSIT_ROUTE_REPLY_ID
+        )]
+    );
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "deposit_routed"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("asset", TEST_OFFER_DENOM),
+        ]
+    );
+
+    let resp = exec_reply(&mut deps, 100).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "deposit"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("swapped_in", "100"),
+            attr("swapped_out", "1000"),
+        ]
+    );
+    assert_swapped_in(&deps, 100);
+}
+
+#[test]
+fn success_cw20() {
+    let mut deps = mock_deps();
+    init_with_route(&mut deps, TEST_OFFER_TOKEN);
+
+    let resp = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OFFER_TOKEN, &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: TEST_USER_1.to_string(),
+            amount: Uint128::from(1000u128),
+            msg: to_binary(&Cw20HookMsg::DepositRouted {
+                min_receive: Uint128::from(90u128),
+                referrer: None,
+            })
+            .unwrap(),
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::reply_on_success(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: TEST_OFFER_TOKEN.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: TEST_PAIR.to_string(),
+                    amount: Uint128::from(1000u128),
+                    msg: to_binary(&PairCw20HookMsg::Swap {
+                        belief_price: None,
+                        max_spread: None,
+                        to: None,
+                    })
+                    .unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            DEPOSIT_ROUTE_REPLY_ID
+        )]
+    );
+
+    exec_reply(&mut deps, 100).unwrap();
+    assert_swapped_in(&deps, 100);
+}
+
+#[test]
+fn fail_min_receive_not_met() {
+    let mut deps = mock_deps();
+    init_with_route(&mut deps, TEST_OFFER_DENOM);
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(1000, TEST_OFFER_DENOM)),
+        Uint128::from(90u128),
+    )
+    .unwrap();
+
+    match exec_reply(&mut deps, 80) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::MinReceiveNotMet {
+            received,
+            min_receive,
+        }) => assert_eq!(
+            (received, min_receive),
+            (Uint128::from(80u128), Uint128::from(90u128))
+        ),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_deposit_route_not_found() {
+    let mut deps = mock_deps();
+    init_with_route(&mut deps, TEST_OFFER_DENOM);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(1000, "ujpy")),
+        Uint128::zero(),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::DepositRouteNotFound { asset }) => assert_eq!(asset, "ujpy"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
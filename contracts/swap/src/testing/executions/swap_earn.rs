@@ -1,53 +1,152 @@
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{attr, coins, BankMsg, CosmosMsg, Env, MessageInfo, SubMsg, Timestamp};
-use pylon_gateway::swap_msg::ExecuteMsg;
+use cosmwasm_std::{attr, coins, BankMsg, CosmosMsg, Env, MessageInfo, SubMsg, Timestamp, Uint128};
+use pylon_gateway::swap_msg::{ExecuteMsg, InstantiateMsg};
 
 use crate::constants::EARN_LOCK_PERIOD;
 use crate::entrypoints::execute;
 use crate::error::ContractError;
 use crate::executions::ExecuteResult;
+use crate::states::state::State;
 use crate::testing::{
     instantiate, mock_deps, mock_deps_with_balance, MockDeps, TEST_BENEFICIARY, TEST_OWNER,
+    TEST_USER_1, TEST_USER_2,
 };
 
 pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
-    execute(deps.as_mut(), env, info, ExecuteMsg::Earn {})
+    exec_amount(deps, env, info, None)
 }
 
-#[test]
-fn success() {
-    const SWAP_AMOUNT: u128 = 100000;
-    let default_msg = instantiate::default_msg();
-    let mut deps = mock_deps_with_balance(&coins(SWAP_AMOUNT, default_msg.input_token.clone()));
+pub fn exec_amount(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    amount: Option<Uint128>,
+) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::Earn { amount })
+}
+
+const SWAP_IN_AMOUNT: u128 = 100;
+
+fn init_with_deposits(msg: InstantiateMsg, users: &[&str]) -> MockDeps {
+    let total_in = SWAP_IN_AMOUNT * users.len() as u128;
+    let mut deps = mock_deps_with_balance(&coins(total_in, msg.input_token.clone()));
     instantiate::exec(
         &mut deps,
         mock_env(),
-        mock_info(
-            TEST_OWNER,
-            &coins(SWAP_AMOUNT, default_msg.input_token.clone()),
-        ),
-        default_msg.clone(),
+        mock_info(TEST_OWNER, &[]),
+        msg.clone(),
     )
     .unwrap();
 
+    for user in users {
+        super::swap_deposit::exec(
+            &mut deps,
+            mock_env(),
+            mock_info(user, &coins(SWAP_IN_AMOUNT, msg.input_token.clone())),
+        )
+        .unwrap();
+    }
+
+    deps
+}
+
+fn earn_env() -> Env {
+    let default_msg = instantiate::default_msg();
     let mut env = mock_env();
     env.block.time =
         Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
-    let res = exec(&mut deps, env, mock_info(TEST_BENEFICIARY, &[])).unwrap();
+    env
+}
+
+#[test]
+fn success() {
+    let default_msg = instantiate::default_msg();
+    let mut deps = init_with_deposits(default_msg.clone(), &[TEST_USER_1]);
+
+    let res = exec(&mut deps, earn_env(), mock_info(TEST_BENEFICIARY, &[])).unwrap();
     assert_eq!(
         res.messages,
         vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
             to_address: TEST_BENEFICIARY.to_string(),
-            amount: coins(SWAP_AMOUNT, default_msg.input_token)
+            amount: coins(SWAP_IN_AMOUNT, default_msg.input_token)
         }))]
     );
     assert_eq!(
         res.attributes,
         vec![
             attr("action", "earn"),
-            attr("sender", TEST_BENEFICIARY.to_string())
+            attr("sender", TEST_BENEFICIARY.to_string()),
+            attr("amount", SWAP_IN_AMOUNT.to_string()),
+            attr("protocol_fee", "0"),
+            attr("total_protocol_fee_input", "0"),
+            attr("total_protocol_fee_output", "0"),
         ]
     );
+
+    let state = State::load(deps.as_ref().storage).unwrap();
+    assert_eq!(state.total_raised, Uint128::from(SWAP_IN_AMOUNT));
+    assert_eq!(state.total_earned, Uint128::from(SWAP_IN_AMOUNT));
+}
+
+#[test]
+fn success_partial() {
+    let default_msg = instantiate::default_msg();
+    let mut deps = init_with_deposits(default_msg.clone(), &[TEST_USER_1]);
+
+    exec_amount(
+        &mut deps,
+        earn_env(),
+        mock_info(TEST_BENEFICIARY, &[]),
+        Some(Uint128::from(40u128)),
+    )
+    .unwrap();
+
+    match exec_amount(
+        &mut deps,
+        earn_env(),
+        mock_info(TEST_BENEFICIARY, &[]),
+        Some(Uint128::from(61u128)),
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::EarnAmountExceeded { available }) => {
+            assert_eq!(available, Uint128::from(60u128))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    let res = exec(&mut deps, earn_env(), mock_info(TEST_BENEFICIARY, &[])).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_BENEFICIARY.to_string(),
+            amount: coins(60, default_msg.input_token)
+        }))]
+    );
+}
+
+#[test]
+fn success_earn_by_claim() {
+    let mut default_msg = instantiate::default_msg();
+    default_msg.earn_by_claim = true;
+    let mut deps = init_with_deposits(default_msg.clone(), &[TEST_USER_1, TEST_USER_2]);
+
+    // nothing claimed yet
+    match exec(&mut deps, earn_env(), mock_info(TEST_BENEFICIARY, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowZeroAmount {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    super::swap_claim::exec(&mut deps, earn_env(), mock_info(TEST_USER_1, &[])).unwrap();
+
+    let res = exec(&mut deps, earn_env(), mock_info(TEST_BENEFICIARY, &[])).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_BENEFICIARY.to_string(),
+            amount: coins(SWAP_IN_AMOUNT, default_msg.input_token)
+        }))]
+    );
 }
 
 #[test]
@@ -0,0 +1,147 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, Api, BankMsg, CosmosMsg, Env, MessageInfo, SubMsg, Timestamp, Uint128,
+};
+use pylon_gateway::swap_msg::ExecuteMsg;
+
+use crate::constants::EARN_LOCK_PERIOD;
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::state::State;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_BENEFICIARY, TEST_OWNER, TEST_USER_1};
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::Refund {})
+}
+
+pub fn exec_cancel(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::Cancel {})
+}
+
+const SWAP_IN_AMOUNT: u128 = 100;
+
+fn init_with_deposit(deps: &mut MockDeps, soft_cap: Option<Uint128>) {
+    let mut default_msg = instantiate::default_msg();
+    default_msg.soft_cap = soft_cap;
+    instantiate::exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg.clone(),
+    )
+    .unwrap();
+
+    super::swap_deposit::exec(
+        deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(SWAP_IN_AMOUNT, default_msg.input_token)),
+    )
+    .unwrap();
+}
+
+fn assert_refund_response(deps: &MockDeps, resp: ExecuteResult) {
+    let default_msg = instantiate::default_msg();
+    let resp = resp.unwrap();
+    assert_eq!(
+        resp.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: TEST_USER_1.to_string(),
+            amount: coins(SWAP_IN_AMOUNT, default_msg.input_token)
+        }))]
+    );
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "refund"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("amount", SWAP_IN_AMOUNT.to_string()),
+        ]
+    );
+
+    assert_eq!(
+        User::load(
+            deps.as_ref().storage,
+            &deps.api.addr_canonicalize(TEST_USER_1).unwrap()
+        ),
+        User::default()
+    );
+    assert_eq!(
+        State::load(deps.as_ref().storage).unwrap().total_swapped,
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn success_cancelled() {
+    let mut deps = mock_deps();
+    init_with_deposit(&mut deps, None);
+
+    let resp = exec_cancel(&mut deps, mock_env(), mock_info(TEST_OWNER, &[])).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "cancel"),
+            attr("sender", TEST_OWNER.to_string())
+        ]
+    );
+
+    let resp = exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[]));
+    assert_refund_response(&deps, resp);
+}
+
+#[test]
+fn success_soft_cap_failed() {
+    let mut deps = mock_deps();
+    init_with_deposit(&mut deps, Some(Uint128::from(10000u128)));
+    let default_msg = instantiate::default_msg();
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + default_msg.period + 1);
+    let resp = exec(&mut deps, env, mock_info(TEST_USER_1, &[]));
+    assert_refund_response(&deps, resp);
+}
+
+#[test]
+fn fail_not_allow_refund() {
+    let mut deps = mock_deps();
+    init_with_deposit(&mut deps, Some(Uint128::from(10000u128)));
+
+    // before finish
+    match exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowRefund {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_not_allow_earn_in_refund_mode() {
+    let mut deps = mock_deps();
+    init_with_deposit(&mut deps, None);
+    let default_msg = instantiate::default_msg();
+
+    exec_cancel(&mut deps, mock_env(), mock_info(TEST_OWNER, &[])).unwrap();
+
+    let mut env = mock_env();
+    env.block.time =
+        Timestamp::from_seconds(default_msg.start + default_msg.period + EARN_LOCK_PERIOD);
+    match super::swap_earn::exec(&mut deps, env, mock_info(TEST_BENEFICIARY, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowInRefundMode {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_cancel_unauthorized() {
+    let mut deps = mock_deps();
+    init_with_deposit(&mut deps, None);
+
+    match exec_cancel(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => assert_eq!(action, "cancel"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
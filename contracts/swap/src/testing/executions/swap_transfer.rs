@@ -0,0 +1,256 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Api, Decimal, Env, MessageInfo, Timestamp, Uint128};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg, QueryMsg};
+use pylon_gateway::swap_types;
+
+use crate::entrypoints::{execute, query};
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
+
+pub const TEST_RECEIPT_TOKEN: &str = "terra1757tkx08n0cqrw7p86ny9lnxsqeth0wgp0em95";
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    owner: &str,
+    recipient: &str,
+    amount: u128,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::TransferInternal {
+            owner: owner.to_string(),
+            recipient: recipient.to_string(),
+            amount: Uint128::from(amount),
+        },
+    )
+}
+
+fn init_with_receipt_token(deps: &mut MockDeps) {
+    instantiate::default(deps);
+    configure_receipt_token(deps);
+}
+
+pub fn configure_receipt_token(deps: &mut MockDeps) {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::Config {
+            beneficiary: None,
+            input_token: None,
+            output_token: None,
+            deposit_cap_strategy: None,
+            distribution_strategies: None,
+            whitelist_enabled: None,
+            receipt_token: Some(TEST_RECEIPT_TOKEN.to_string()),
+            earn_by_claim: None,
+        })),
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    init_with_receipt_token(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+    )
+    .unwrap();
+
+    // pretend half of the allocation has been claimed
+    let api = deps.api;
+    let owner_addr = api.addr_canonicalize(TEST_USER_1).unwrap();
+    let mut owner = User::load(deps.as_ref().storage, &owner_addr);
+    owner.swapped_out_claimed = Uint128::from(500u128);
+    User::save(deps.as_mut().storage, &owner_addr, &owner).unwrap();
+
+    let resp = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_RECEIPT_TOKEN, &[]),
+        TEST_USER_1,
+        TEST_USER_2,
+        250,
+    )
+    .unwrap();
+    assert_eq!(resp.attributes, vec![attr("action", "transfer_internal")]);
+
+    assert_eq!(
+        User::load(deps.as_ref().storage, &owner_addr),
+        User {
+            swapped_in: Uint128::from(50u128),
+            swapped_out: Uint128::from(500u128),
+            swapped_out_claimed: Uint128::from(250u128),
+        }
+    );
+    assert_eq!(
+        User::load(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_2).unwrap()
+        ),
+        User {
+            swapped_in: Uint128::from(50u128),
+            swapped_out: Uint128::from(500u128),
+            swapped_out_claimed: Uint128::from(250u128),
+        }
+    );
+
+    // both sides keep the same schedule
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + default_msg.period + 1);
+    let resp = super::swap_claim::exec(&mut deps, env, mock_info(TEST_USER_2, &[])).unwrap();
+    assert_eq!(resp.attributes[2], attr("amount", "250"));
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    init_with_receipt_token(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        TEST_USER_1,
+        TEST_USER_2,
+        100,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => {
+            assert_eq!(action, "transfer_internal")
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_transfer_amount_exceeded() {
+    let mut deps = mock_deps();
+    init_with_receipt_token(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(100u128, default_msg.input_token)),
+    )
+    .unwrap();
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_RECEIPT_TOKEN, &[]),
+        TEST_USER_1,
+        TEST_USER_2,
+        1001,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::TransferAmountExceeded { available }) => {
+            assert_eq!(available, Uint128::from(1000u128))
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_paused() {
+    let mut deps = mock_deps();
+    init_with_receipt_token(&mut deps);
+    super::config_pause::exec(&mut deps, TEST_OWNER, None, Some(true), None, None).unwrap();
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_RECEIPT_TOKEN, &[]),
+        TEST_USER_1,
+        TEST_USER_2,
+        1,
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Paused { action }) => assert_eq!(action, "withdraw"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn success_round_up_moved_claimed() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    let now = mock_env().block.time.seconds();
+    // a third is released
+    default_msg.distribution_strategies = vec![
+        swap_types::DistributionStrategy::Lockup {
+            release_time: now,
+            release_amount: Decimal::from_ratio(1u128, 3u128),
+        },
+        swap_types::DistributionStrategy::Lockup {
+            release_time: now + 100,
+            release_amount: Decimal::from_ratio(2u128, 3u128),
+        },
+    ];
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg,
+    )
+    .unwrap();
+    configure_receipt_token(&mut deps);
+
+    let api = deps.api;
+    let owner_addr = api.addr_canonicalize(TEST_USER_1).unwrap();
+    User::save(
+        deps.as_mut().storage,
+        &owner_addr,
+        &User {
+            swapped_in: Uint128::from(100000u128),
+            swapped_out: Uint128::from(1000000u128),
+            swapped_out_claimed: Uint128::from(333333u128),
+        },
+    )
+    .unwrap();
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_RECEIPT_TOKEN, &[]),
+        TEST_USER_1,
+        TEST_USER_2,
+        1,
+    )
+    .unwrap();
+
+    let owner = User::load(deps.as_ref().storage, &owner_addr);
+    assert_eq!(
+        owner,
+        User {
+            swapped_in: Uint128::from(100000u128),
+            swapped_out: Uint128::from(999998u128),
+            swapped_out_claimed: Uint128::from(333332u128),
+        }
+    );
+    assert!(owner.swapped_out_claimed <= owner.swapped_out * Decimal::from_ratio(1u128, 3u128));
+
+    // claimable of both sides can be computed
+    for address in [TEST_USER_1, TEST_USER_2].iter() {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ClaimableTokenOf {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,229 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, coins, from_binary, to_binary, Api, Env, MessageInfo, Uint128};
+use pylon_gateway::swap_msg::ExecuteMsg;
+use pylon_gateway::swap_types;
+use pylon_token::{gov_msg, gov_resp};
+
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_GOV, TEST_OWNER, TEST_USER_1};
+
+const GOV_STAKE_AMOUNT: u128 = 1000;
+
+pub fn exec(deps: &mut MockDeps, env: Env, info: MessageInfo) -> ExecuteResult {
+    execute(deps.as_mut(), env, info, ExecuteMsg::Register {})
+}
+
+fn snapshot_strategy() -> swap_types::CapStrategy {
+    swap_types::CapStrategy::GovSnapshot {
+        registration_contract: Some(TEST_GOV.to_string()),
+        strategy: Box::new(swap_types::CapStrategy::GovFixed {
+            contract: TEST_GOV.to_string(),
+            min_stake_amount: Uint128::from(GOV_STAKE_AMOUNT),
+            min_user_cap: None,
+            max_user_cap: Some(Uint128::from(100u128)),
+        }),
+    }
+}
+
+fn init_with_strategy(deps: &mut MockDeps, strategy: Option<swap_types::CapStrategy>) {
+    let mut default_msg = instantiate::default_msg();
+    default_msg.start = mock_env().block.time.seconds() + 10;
+    default_msg.deposit_cap_strategy = strategy;
+    instantiate::exec(deps, mock_env(), mock_info(TEST_OWNER, &[]), default_msg).unwrap();
+
+    register_gov_stake(deps, GOV_STAKE_AMOUNT);
+}
+
+fn register_gov_stake(deps: &mut MockDeps, stake: u128) {
+    deps.querier.register_wasm_smart_query_handler(
+        TEST_GOV.to_string(),
+        Box::new(
+            move |x| match from_binary::<gov_msg::QueryMsg>(x).unwrap() {
+                gov_msg::QueryMsg::Staker { .. } => to_binary(&gov_resp::StakerResponse {
+                    balance: Uint128::from(stake),
+                    share: Uint128::from(stake),
+                    claimable_airdrop: vec![],
+                    locked_balance: vec![],
+                }),
+                _ => panic!("Unsupported query"),
+            },
+        ),
+    );
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, Some(snapshot_strategy()));
+
+    let resp = exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])).unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![
+            attr("action", "register"),
+            attr("sender", TEST_USER_1.to_string()),
+            attr("stake", GOV_STAKE_AMOUNT.to_string()),
+        ]
+    );
+
+    let api = deps.api;
+    assert_eq!(
+        User::load_stake_snapshot(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap()
+        )
+        .unwrap(),
+        Some(Uint128::from(GOV_STAKE_AMOUNT))
+    );
+}
+
+#[test]
+fn success_snapshot_phase_in_schedule() {
+    let mut deps = mock_deps();
+    let start = mock_env().block.time.seconds() + 10;
+    init_with_strategy(
+        &mut deps,
+        Some(swap_types::CapStrategy::Scheduled {
+            phases: vec![
+                (
+                    start,
+                    swap_types::CapStrategy::Fixed {
+                        min_user_cap: None,
+                        max_user_cap: Some(Uint128::from(100u128)),
+                    },
+                ),
+                (start + 100, snapshot_strategy()),
+            ],
+        }),
+    );
+
+    // the snapshot phase is not started yet
+    exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])).unwrap();
+
+    let api = deps.api;
+    assert_eq!(
+        User::load_stake_snapshot(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap()
+        )
+        .unwrap(),
+        Some(Uint128::from(GOV_STAKE_AMOUNT))
+    );
+}
+
+#[test]
+fn success_deposit_with_snapshot() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, Some(snapshot_strategy()));
+    exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])).unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(10);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(TEST_USER_1, &coins(100u128, "uusd")),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: None,
+        },
+    )
+    .unwrap();
+
+    // cap is evaluated with the registered stake, not the current gov balance
+    match execute(
+        deps.as_mut(),
+        env,
+        mock_info(TEST_USER_1, &coins(1u128, "uusd")),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: None,
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AvailableCapExceeded { available }) => {
+            assert_eq!(available, Uint128::zero())
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_deposit_after_unstake() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, Some(snapshot_strategy()));
+    exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])).unwrap();
+
+    // stake withdrawn from gov after registration
+    register_gov_stake(&mut deps, GOV_STAKE_AMOUNT - 1);
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(10);
+    match execute(
+        deps.as_mut(),
+        env,
+        mock_info(TEST_USER_1, &coins(100u128, "uusd")),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: None,
+        },
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AvailableCapExceeded { available }) => {
+            assert_eq!(available, Uint128::zero())
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_already_registered() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, Some(snapshot_strategy()));
+
+    // owner-submitted snapshot must be kept
+    let api = deps.api;
+    let user = api.addr_canonicalize(TEST_USER_1).unwrap();
+    User::save_stake_snapshot(deps.as_mut().storage, &user, &Uint128::from(10u128)).unwrap();
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::AlreadyRegistered {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+    assert_eq!(
+        User::load_stake_snapshot(deps.as_ref().storage, &user).unwrap(),
+        Some(Uint128::from(10u128))
+    );
+}
+
+#[test]
+fn fail_not_allow_register_after_start() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, Some(snapshot_strategy()));
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(10);
+    match exec(&mut deps, env, mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowRegisterAfterStart { start }) => {
+            assert_eq!(start, mock_env().block.time.seconds() + 10)
+        }
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_registration_not_enabled() {
+    let mut deps = mock_deps();
+    init_with_strategy(&mut deps, None);
+
+    match exec(&mut deps, mock_env(), mock_info(TEST_USER_1, &[])) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::RegistrationNotEnabled {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,71 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, Api, Env, MessageInfo, Uint128};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg};
+
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    stakes: Vec<(String, Uint128)>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::Snapshot { stakes })),
+    )
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let resp = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        vec![
+            (TEST_USER_1.to_string(), Uint128::from(100u128)),
+            (TEST_USER_2.to_string(), Uint128::from(200u128)),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![attr("action", "snapshot_user"), attr("count", "2")]
+    );
+
+    let api = deps.api;
+    assert_eq!(
+        User::load_stake_snapshot(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_2).unwrap()
+        )
+        .unwrap(),
+        Some(Uint128::from(200u128))
+    );
+}
+
+#[test]
+fn fail_unauthorized() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &[]),
+        vec![(TEST_USER_1.to_string(), Uint128::from(100u128))],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::Unauthorized { action, .. }) => assert_eq!(action, "snapshot"),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
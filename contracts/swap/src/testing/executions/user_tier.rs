@@ -0,0 +1,277 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Api, Decimal, Env, MessageInfo, Timestamp};
+use pylon_gateway::swap_msg::{ConfigureMsg, ExecuteMsg};
+use pylon_gateway::swap_types;
+
+use crate::entrypoints::execute;
+use crate::error::ContractError;
+use crate::executions::ExecuteResult;
+use crate::states::user::User;
+use crate::testing::{instantiate, mock_deps, MockDeps, TEST_OWNER, TEST_USER_1, TEST_USER_2};
+
+const TEST_TIER: &str = "private";
+
+pub fn exec(
+    deps: &mut MockDeps,
+    env: Env,
+    info: MessageInfo,
+    tier: Option<String>,
+    candidates: Vec<String>,
+) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Configure(Box::new(ConfigureMsg::Tier { tier, candidates })),
+    )
+}
+
+fn configure(deps: &mut MockDeps, msg: ConfigureMsg) -> ExecuteResult {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        ExecuteMsg::Configure(Box::new(msg)),
+    )
+}
+
+fn init_with_tier(deps: &mut MockDeps) {
+    instantiate::default(deps);
+    let default_msg = instantiate::default_msg();
+
+    configure(
+        deps,
+        ConfigureMsg::TierStrategy {
+            tier: TEST_TIER.to_string(),
+            distribution_strategies: Some(vec![swap_types::DistributionStrategy::Lockup {
+                release_time: default_msg.start + 200,
+                release_amount: Decimal::one(),
+            }]),
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn success() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+
+    let resp = exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_1.to_string()],
+    )
+    .unwrap();
+    assert_eq!(
+        resp.attributes,
+        vec![attr("action", "tier_user"), attr("tier", TEST_TIER)]
+    );
+
+    let api = deps.api;
+    assert_eq!(
+        User::load_tier(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap()
+        ),
+        Some(TEST_TIER.to_string())
+    );
+}
+
+#[test]
+fn success_claim_with_tier_strategies() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_1.to_string()],
+    )
+    .unwrap();
+
+    for user in [TEST_USER_1, TEST_USER_2] {
+        super::swap_deposit::exec(
+            &mut deps,
+            mock_env(),
+            mock_info(user, &coins(500u128, default_msg.input_token.clone())),
+        )
+        .unwrap();
+    }
+
+    // global strategies released, tier strategies locked
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 100);
+    let resp =
+        super::swap_claim::exec(&mut deps, env.clone(), mock_info(TEST_USER_2, &[])).unwrap();
+    assert_eq!(resp.attributes[2], attr("amount", "5000"));
+
+    let resp = super::swap_claim::exec(&mut deps, env, mock_info(TEST_USER_1, &[])).unwrap();
+    assert_eq!(resp.attributes[2], attr("amount", "0"));
+}
+
+#[test]
+fn success_deposit_tier() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    configure(
+        &mut deps,
+        ConfigureMsg::DepositTier {
+            tier: Some(TEST_TIER.to_string()),
+        },
+    )
+    .unwrap();
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(1000u128, default_msg.input_token)),
+    )
+    .unwrap();
+
+    let api = deps.api;
+    assert_eq!(
+        User::load_tier(
+            deps.as_ref().storage,
+            &api.addr_canonicalize(TEST_USER_1).unwrap()
+        ),
+        Some(TEST_TIER.to_string())
+    );
+}
+
+#[test]
+fn fail_tier_not_found() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    match exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_1.to_string()],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::TierNotFound { tier }) => assert_eq!(tier, TEST_TIER),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+fn deposit_for(deps: &mut MockDeps, sender: &str, recipient: &str) -> ExecuteResult {
+    let default_msg = instantiate::default_msg();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(sender, &coins(1000u128, default_msg.input_token)),
+        ExecuteMsg::Deposit {
+            referrer: None,
+            recipient: Some(recipient.to_string()),
+        },
+    )
+}
+
+#[test]
+fn fail_not_allow_deposit_between_tiers() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_2.to_string()],
+    )
+    .unwrap();
+    match deposit_for(&mut deps, TEST_USER_1, TEST_USER_2) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowDepositBetweenTiers {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    // recipient outside the tier of the round
+    configure(
+        &mut deps,
+        ConfigureMsg::DepositTier {
+            tier: Some(TEST_TIER.to_string()),
+        },
+    )
+    .unwrap();
+    match deposit_for(&mut deps, TEST_OWNER, TEST_USER_1) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowDepositBetweenTiers {}) => (),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn fail_tier_in_use() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+    let remove_tier = ConfigureMsg::TierStrategy {
+        tier: TEST_TIER.to_string(),
+        distribution_strategies: None,
+    };
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_1.to_string()],
+    )
+    .unwrap();
+    match configure(&mut deps, remove_tier.clone()) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::TierInUse { tier }) => assert_eq!(tier, TEST_TIER),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+
+    exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        None,
+        vec![TEST_USER_1.to_string()],
+    )
+    .unwrap();
+    configure(&mut deps, remove_tier).unwrap();
+}
+
+#[test]
+fn fail_tier_after_claim() {
+    let mut deps = mock_deps();
+    init_with_tier(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    super::swap_deposit::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_USER_1, &coins(500u128, default_msg.input_token)),
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(default_msg.start + 100);
+    super::swap_claim::exec(&mut deps, env.clone(), mock_info(TEST_USER_1, &[])).unwrap();
+
+    // slower schedule would release less than already claimed
+    match exec(
+        &mut deps,
+        env,
+        mock_info(TEST_OWNER, &[]),
+        Some(TEST_TIER.to_string()),
+        vec![TEST_USER_1.to_string()],
+    ) {
+        Ok(_) => panic!("Must return error"),
+        Err(ContractError::NotAllowTierAfterClaim { address }) => assert_eq!(address, TEST_USER_1),
+        Err(e) => panic!("Unexpected error {:?}", e),
+    }
+}
@@ -7,11 +7,13 @@ use pylon_gateway::swap_types;
 
 use crate::constants::{CONTRACT_NAME, CONTRACT_VERSION};
 use crate::entrypoints::instantiate;
+use crate::error::ContractError;
 use crate::executions::ExecuteResult;
 use crate::states::config::Config;
 use crate::states::state::State;
 use crate::testing::{
-    mock_deps, MockDeps, TEST_BENEFICIARY, TEST_INPUT_TOKEN, TEST_OUTPUT_TOKEN, TEST_OWNER,
+    is_valid_bech32, mock_deps, MockDeps, TEST_BENEFICIARY, TEST_INPUT_TOKEN, TEST_OUTPUT_TOKEN,
+    TEST_OWNER, TEST_PROTOCOL_ADMIN,
 };
 use crate::types::distribution_strategy::DistributionStrategy;
 
@@ -51,6 +53,9 @@ pub fn default_msg() -> InstantiateMsg {
             release_amount: Decimal::one(),
         }],
         whitelist_enabled: false,
+        soft_cap: None,
+        earn_by_claim: false,
+        protocol_admin: None,
     }
 }
 
@@ -77,6 +82,8 @@ fn success() {
         Config {
             owner: api.addr_validate(TEST_OWNER).unwrap(),
             beneficiary: api.addr_validate(TEST_BENEFICIARY).unwrap(),
+            proceeds_beneficiaries: vec![],
+            penalty_beneficiaries: vec![],
             start: env.block.time.seconds(),
             finish: env.block.time.seconds() + 100,
             price: Decimal::from_ratio(1u128, 10u128),
@@ -88,7 +95,19 @@ fn success() {
                 release_time: env.block.time.seconds() + 100,
                 release_amount: Decimal::one()
             }],
-            whitelist_enabled: false
+            tier_distribution_strategies: vec![],
+            deposit_tier: None,
+            whitelist_enabled: false,
+            receipt_token: None,
+            soft_cap: None,
+            earn_by_claim: false,
+            protocol_admin: None,
+            protocol_fee_rate: Decimal::zero(),
+            protocol_fee_collector: None,
+            protocol_fee_on_claim: false,
+            liquidity_seed: None,
+            deposit_routes: vec![],
+            referral_reward: None
         }
     );
 
@@ -99,7 +118,165 @@ fn success() {
             total_swapped: Uint128::zero(),
             total_claimed: Uint128::zero(),
             x_liquidity: Uint128::from(10000u128),
-            y_liquidity: Uint128::from(100000u128)
+            y_liquidity: Uint128::from(100000u128),
+            cancelled: false,
+            total_raised: Uint128::zero(),
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero()
         }
     );
 }
+
+#[test]
+fn fail_invalid_cap_strategy() {
+    let fixed = |cap: u128| swap_types::CapStrategy::Fixed {
+        min_user_cap: None,
+        max_user_cap: Some(Uint128::from(cap)),
+    };
+
+    for (strategy, expected) in [
+        (
+            swap_types::CapStrategy::Fixed {
+                min_user_cap: Some(Uint128::from(2u128)),
+                max_user_cap: Some(Uint128::from(1u128)),
+            },
+            ContractError::InvalidUserCapRange {
+                min: Uint128::from(2u128),
+                max: Uint128::from(1u128),
+            },
+        ),
+        (
+            swap_types::CapStrategy::GovLinear {
+                contract: TEST_OWNER.to_string(),
+                cap_start: Uint128::zero(),
+                cap_weight: Decimal::one(),
+                min_stake_amount: Some(Uint128::from(2u128)),
+                max_stake_amount: Some(Uint128::from(1u128)),
+            },
+            ContractError::InvalidStakeAmountRange {
+                min: Uint128::from(2u128),
+                max: Uint128::from(1u128),
+            },
+        ),
+        (
+            swap_types::CapStrategy::GovStaged {
+                contract: TEST_OWNER.to_string(),
+                stages: vec![
+                    (None, Some(Uint128::from(10u128)), Uint128::from(1u128)),
+                    (Some(Uint128::from(5u128)), None, Uint128::from(2u128)),
+                ],
+            },
+            ContractError::OverlappedStages { index: 1 },
+        ),
+        (
+            swap_types::CapStrategy::Scheduled {
+                phases: vec![(10, fixed(1)), (5, fixed(2))],
+            },
+            ContractError::UnsortedPhases { index: 1 },
+        ),
+        (
+            swap_types::CapStrategy::Min(vec![]),
+            ContractError::EmptyStrategies {},
+        ),
+    ] {
+        let mut deps = mock_deps();
+        let mut msg = default_msg();
+        msg.deposit_cap_strategy = Some(strategy);
+
+        match exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg) {
+            Ok(_) => panic!("Must return error"),
+            Err(e) => assert_eq!(e, expected),
+        }
+    }
+}
+
+#[test]
+fn fail_invalid_distribution_strategies() {
+    let default_blocktime = mock_env().block.time.seconds();
+
+    for (strategies, expected) in [
+        (
+            vec![swap_types::DistributionStrategy::Vesting {
+                release_start_time: default_blocktime + 100,
+                release_finish_time: default_blocktime,
+                release_amount: Decimal::one(),
+            }],
+            ContractError::InvalidReleaseTime {
+                start: default_blocktime + 100,
+                finish: default_blocktime,
+            },
+        ),
+        (
+            vec![swap_types::DistributionStrategy::Lockup {
+                release_time: default_blocktime + 100,
+                release_amount: Decimal::percent(50),
+            }],
+            ContractError::InvalidReleaseAmountSum {
+                sum: Decimal::percent(50),
+            },
+        ),
+        (
+            vec![swap_types::DistributionStrategy::Periodic {
+                start: default_blocktime + 100,
+                interval: 1,
+                count: u64::MAX,
+                amount_per_period: Decimal::from_ratio(1000u128, 1u128),
+            }],
+            ContractError::ReleaseAmountOverflow {
+                amount_per_period: Decimal::from_ratio(1000u128, 1u128),
+                count: u64::MAX,
+            },
+        ),
+    ] {
+        let mut deps = mock_deps();
+        let mut msg = default_msg();
+        msg.distribution_strategies = strategies;
+
+        match exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg) {
+            Ok(_) => panic!("Must return error"),
+            Err(e) => assert_eq!(e, expected),
+        }
+    }
+}
+
+#[test]
+fn success_release_in_thirds() {
+    let default_blocktime = mock_env().block.time.seconds();
+
+    let mut deps = mock_deps();
+    let mut msg = default_msg();
+    msg.distribution_strategies = (1..=3u64)
+        .map(|i| swap_types::DistributionStrategy::Lockup {
+            release_time: default_blocktime + 100 * i,
+            release_amount: Decimal::from_ratio(1u128, 3u128),
+        })
+        .collect();
+
+    exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg).unwrap();
+}
+
+#[test]
+fn success_with_protocol_admin() {
+    let mut deps = mock_deps();
+    let mut msg = default_msg();
+    msg.protocol_admin = Some(TEST_PROTOCOL_ADMIN.to_string());
+    exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg).unwrap();
+
+    let protocol_admin = Config::load(deps.as_ref().storage)
+        .unwrap()
+        .protocol_admin
+        .unwrap();
+    assert_eq!(protocol_admin.as_str(), TEST_PROTOCOL_ADMIN);
+    assert!(is_valid_bech32(protocol_admin.as_str()));
+    // checksum mismatch
+    assert!(!is_valid_bech32(
+        "terra1ztzjk3gqhmgulfqf9gu8pdp2ymcdz6dgy6tuhj"
+    ));
+}
@@ -0,0 +1,55 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::Uint128;
+use pylon_gateway::swap_msg::MigrateMsg;
+
+use crate::entrypoints::migrate;
+use crate::states::config::Config;
+use crate::states::state::State;
+use crate::testing::{instantiate, mock_deps, TEST_OWNER, TEST_PROTOCOL_ADMIN};
+
+#[test]
+fn success_backfill_total_raised() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+    let default_msg = instantiate::default_msg();
+
+    let mut state = State::load(deps.as_ref().storage).unwrap();
+    state.total_swapped = Uint128::from(1000u128);
+    state.total_raised = Uint128::zero();
+    State::save(deps.as_mut().storage, &state).unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::General {}).unwrap();
+    assert_eq!(
+        State::load(deps.as_ref().storage).unwrap().total_raised,
+        Uint128::from(1000u128) * default_msg.price
+    );
+}
+
+#[test]
+fn success_keep_tracked_total_raised() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let mut state = State::load(deps.as_ref().storage).unwrap();
+    state.total_swapped = Uint128::from(1000u128);
+    state.total_raised = Uint128::from(7u128);
+    State::save(deps.as_mut().storage, &state).unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::General {}).unwrap();
+    assert_eq!(
+        State::load(deps.as_ref().storage).unwrap().total_raised,
+        Uint128::from(7u128)
+    );
+}
+
+#[test]
+fn success_keep_protocol_admin() {
+    let mut deps = mock_deps();
+    let mut msg = instantiate::default_msg();
+    msg.protocol_admin = Some(TEST_PROTOCOL_ADMIN.to_string());
+    instantiate::exec(&mut deps, mock_env(), mock_info(TEST_OWNER, &[]), msg).unwrap();
+    let config = Config::load(deps.as_ref().storage).unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::General {}).unwrap();
+    assert_eq!(Config::load(deps.as_ref().storage).unwrap(), config);
+}
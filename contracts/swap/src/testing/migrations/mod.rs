@@ -1,3 +1,4 @@
+mod general;
 mod nexus;
 mod pylon;
 mod valkyrie;
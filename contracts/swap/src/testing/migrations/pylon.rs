@@ -93,6 +93,8 @@ fn success() {
         Config {
             owner: api.addr_validate(TEST_OWNER).unwrap(),
             beneficiary: api.addr_validate(TEST_BENEFICIARY).unwrap(),
+            proceeds_beneficiaries: vec![],
+            penalty_beneficiaries: vec![],
             start: default_msg.start,
             finish: default_msg.start + default_msg.period,
             price: default_msg.price,
@@ -107,7 +109,19 @@ fn success() {
                 release_time: default_msg.start + default_msg.period,
                 release_amount: Decimal::one(),
             }],
-            whitelist_enabled: false
+            tier_distribution_strategies: vec![],
+            deposit_tier: None,
+            whitelist_enabled: false,
+            receipt_token: None,
+            soft_cap: None,
+            earn_by_claim: false,
+            protocol_admin: None,
+            protocol_fee_rate: Decimal::zero(),
+            protocol_fee_collector: None,
+            protocol_fee_on_claim: false,
+            liquidity_seed: None,
+            deposit_routes: vec![],
+            referral_reward: None
         }
     );
 
@@ -117,7 +131,18 @@ fn success() {
             total_swapped: default_msg.amount,
             total_claimed: default_msg.amount - Uint128::from(10000u128),
             x_liquidity: default_msg.x_liquidity,
-            y_liquidity: default_msg.y_liquidity
+            y_liquidity: default_msg.y_liquidity,
+            cancelled: false,
+            total_raised: default_msg.amount * default_msg.price,
+            total_refunded: Uint128::zero(),
+            total_earned: Uint128::zero(),
+            total_protocol_fee_input: Uint128::zero(),
+            total_protocol_fee_output: Uint128::zero(),
+            liquidity_seeded: false,
+            liquidity_seed_input: Uint128::zero(),
+            total_referral_reward_in: Uint128::zero(),
+            total_referral_reward_out: Uint128::zero(),
+            total_referral_reward_out_claimed: Uint128::zero()
         }
     );
 
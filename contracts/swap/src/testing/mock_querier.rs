@@ -87,6 +87,10 @@ impl CustomMockWasmQuerier {
         self.wasm_raw_query_handlers.insert(address, handler);
     }
 
+    pub fn update_balance(&mut self, address: String, balance: Vec<Coin>) {
+        self.base.update_balance(address, balance);
+    }
+
     fn handle_query(&self, request: &QueryRequest<TerraQueryWrapper>) -> QuerierResult {
         match request {
             QueryRequest::Custom(TerraQueryWrapper { route, query_data }) => {
@@ -15,6 +15,8 @@ const TEST_USER_1: &str = "terra1e8ryd9ezefuucd4mje33zdms9m2s90m57878v9";
 const TEST_USER_2: &str = "terra17lmam6zguazs5q5u6z5mmx76uj63gldnse2pdp";
 const TEST_INPUT_TOKEN: &str = "uusd";
 const TEST_OUTPUT_TOKEN: &str = "terra17tv2hvwpg0ukqgd2y5ct2w54fyan7z0zxrm2f9";
+const TEST_GOV: &str = "terra1xu8utj38xuw6mjwck4n97enmavlv852zkcvhgp";
+const TEST_PROTOCOL_ADMIN: &str = "terra16hc7yqlvyfkr7p5kc3vhr32hclg6239v0lgew6";
 
 type MockDeps = OwnedDeps<MockStorage, MockApi, CustomMockWasmQuerier>;
 
@@ -33,3 +35,41 @@ fn mock_env_height(height: u64, time: u64) -> Env {
     env.block.time = Timestamp::from_seconds(time);
     env
 }
+
+// MockApi skips the checksum, so addresses that must be valid on chain are checked here
+fn is_valid_bech32(address: &str) -> bool {
+    const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+    const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    let (hrp, data) = match address.rfind('1') {
+        Some(index) => (&address[..index], &address[index + 1..]),
+        None => return false,
+    };
+    let data: Option<Vec<u32>> = data
+        .chars()
+        .map(|c| CHARSET.find(c).map(|x| x as u32))
+        .collect();
+    let data = match data {
+        Some(data) if 6 <= data.len() => data,
+        _ => return false,
+    };
+
+    let values = hrp
+        .bytes()
+        .map(|x| u32::from(x >> 5))
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|x| u32::from(x & 0x1f)))
+        .chain(data);
+    let polymod = values.fold(1u32, |chk, value| {
+        let top = chk >> 25;
+        let mut chk = (chk & 0x1ffffff) << 5 ^ value;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+        chk
+    });
+
+    polymod == 1
+}
@@ -1 +1,112 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{from_binary, Decimal, Timestamp, Uint128};
+use pylon_gateway::swap_msg::QueryMsg;
+use pylon_gateway::swap_resp_v2::{CapPhaseResponse, ValidateConfigResponse};
+use pylon_gateway::swap_types;
 
+use crate::entrypoints::query;
+use crate::error::ContractError;
+use crate::testing::{instantiate, mock_deps, TEST_OWNER};
+
+#[test]
+fn cap_phase() {
+    let mut deps = mock_deps();
+    let mut default_msg = instantiate::default_msg();
+    let fixed = |cap: u128| swap_types::CapStrategy::Fixed {
+        min_user_cap: None,
+        max_user_cap: Some(Uint128::from(cap)),
+    };
+    let start = default_msg.start;
+    default_msg.deposit_cap_strategy = Some(swap_types::CapStrategy::Scheduled {
+        phases: vec![(start + 10, fixed(50)), (start + 20, fixed(70))],
+    });
+    instantiate::exec(
+        &mut deps,
+        mock_env(),
+        mock_info(TEST_OWNER, &[]),
+        default_msg,
+    )
+    .unwrap();
+
+    for (elapsed, expected) in [
+        (
+            0u64,
+            CapPhaseResponse {
+                phase: None,
+                started_at: None,
+                next_phase_at: None,
+            },
+        ),
+        (
+            15u64,
+            CapPhaseResponse {
+                phase: Some(0),
+                started_at: Some(start + 10),
+                next_phase_at: Some(start + 20),
+            },
+        ),
+        (
+            20u64,
+            CapPhaseResponse {
+                phase: Some(1),
+                started_at: Some(start + 20),
+                next_phase_at: None,
+            },
+        ),
+    ] {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(start + elapsed);
+        let response = query(deps.as_ref(), env, QueryMsg::CapPhase {}).unwrap();
+        assert_eq!(
+            from_binary::<CapPhaseResponse>(&response).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn validate_config() {
+    let mut deps = mock_deps();
+    instantiate::default(&mut deps);
+
+    let lockup = |amount: Decimal| swap_types::DistributionStrategy::Lockup {
+        release_time: mock_env().block.time.seconds() + 100,
+        release_amount: amount,
+    };
+
+    for (strategies, expected) in [
+        (
+            vec![lockup(Decimal::from_ratio(1u128, 3u128)); 3],
+            ValidateConfigResponse {
+                valid: true,
+                error: None,
+            },
+        ),
+        (
+            vec![lockup(Decimal::percent(60)); 2],
+            ValidateConfigResponse {
+                valid: false,
+                error: Some(
+                    ContractError::InvalidReleaseAmountSum {
+                        sum: Decimal::percent(120),
+                    }
+                    .to_string(),
+                ),
+            },
+        ),
+    ] {
+        let response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ValidateConfig {
+                deposit_cap_strategy: None,
+                distribution_strategies: Some(strategies),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            from_binary::<ValidateConfigResponse>(&response).unwrap(),
+            expected
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Decimal, QuerierWrapper, Uint128};
+use cosmwasm_std::{Decimal, Deps, QuerierWrapper, StdResult, Uint128};
 use pylon_gateway::swap_types;
 use pylon_token::gov_msg;
 use pylon_token::gov_resp;
@@ -6,6 +6,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::{max, min};
 
+use crate::error::ContractError;
+use crate::states::user::User;
+
 pub type CapStrategyResult = (Uint128, bool);
 
 pub const ERROR: CapStrategyResult = (Uint128::zero(), false);
@@ -46,6 +49,24 @@ pub enum CapStrategy {
         // 4. cap_weight
         stages: Vec<(Option<Uint128>, Option<Uint128>, Uint128, Decimal)>,
     },
+    GovSnapshot {
+        // gov contract queried on registration.
+        // only owner-submitted snapshots are used if None
+        registration_contract: Option<String>,
+        // evaluated with the recorded stake instead of the current gov balance
+        strategy: Box<CapStrategy>,
+    },
+    // smallest available cap of all strategies
+    Min(Vec<CapStrategy>),
+    // largest available cap of all strategies
+    Max(Vec<CapStrategy>),
+    // sum of caps of all strategies
+    Sum(Vec<CapStrategy>),
+    Scheduled {
+        // 1. phase start time
+        // 2. strategy applied from the start time
+        phases: Vec<(u64, CapStrategy)>,
+    },
 }
 
 impl CapStrategy {
@@ -53,25 +74,174 @@ impl CapStrategy {
     // 2. unlimited
     pub fn available_cap_of(
         self,
-        querier: QuerierWrapper,
+        deps: Deps,
+        time: u64,
         address: String,
         amount: Uint128,
-    ) -> CapStrategyResult {
+    ) -> StdResult<CapStrategyResult> {
+        self.available_cap_with_snapshot(deps, time, address, amount, None)
+    }
+
+    // 1. phase index
+    // 2. phase start time
+    // 3. next phase start time
+    pub fn current_phase(&self, time: u64) -> Option<(u32, u64, Option<u64>)> {
+        match self {
+            Self::Scheduled { phases } => {
+                let (index, (start, _)) = phases
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (start, _))| *start <= time)
+                    .max_by_key(|(_, (start, _))| *start)?;
+                let next = phases
+                    .iter()
+                    .map(|(start, _)| *start)
+                    .filter(|start| time < *start)
+                    .min();
+
+                Some((index as u32, *start, next))
+            }
+            Self::GovSnapshot { strategy, .. } => strategy.current_phase(time),
+            Self::Min(strategies) | Self::Max(strategies) | Self::Sum(strategies) => strategies
+                .iter()
+                .find_map(|strategy| strategy.current_phase(time)),
+            _ => None,
+        }
+    }
+
+    // registration happens before the sale, so upcoming phases are looked up as well
+    pub fn registration_contract(&self, time: u64) -> Option<String> {
+        match self {
+            Self::GovSnapshot {
+                registration_contract,
+                ..
+            } => registration_contract.clone(),
+            Self::Min(strategies) | Self::Max(strategies) | Self::Sum(strategies) => strategies
+                .iter()
+                .find_map(|strategy| strategy.registration_contract(time)),
+            Self::Scheduled { phases } => {
+                let active = phases
+                    .iter()
+                    .map(|(start, _)| *start)
+                    .filter(|start| *start <= time)
+                    .max()
+                    .unwrap_or(0);
+                phases
+                    .iter()
+                    .filter(|(start, _)| active <= *start)
+                    .find_map(|(_, strategy)| strategy.registration_contract(time))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
         match self {
             Self::Fixed {
                 min_user_cap,
                 max_user_cap,
-            } => Self::handle_fixed_strategy(querier, address, amount, min_user_cap, max_user_cap),
+            }
+            | Self::GovFixed {
+                min_user_cap,
+                max_user_cap,
+                ..
+            } => Self::validate_range(*min_user_cap, *max_user_cap)
+                .map_err(|(min, max)| ContractError::InvalidUserCapRange { min, max }),
+            Self::GovLinear {
+                min_stake_amount,
+                max_stake_amount,
+                ..
+            } => Self::validate_range(*min_stake_amount, *max_stake_amount)
+                .map_err(|(min, max)| ContractError::InvalidStakeAmountRange { min, max }),
+            Self::GovStaged { stages, .. } => {
+                Self::validate_stages(stages.iter().map(|(from, to, _)| (*from, *to)).collect())
+            }
+            Self::GovLinearStaged { stages, .. } => {
+                Self::validate_stages(stages.iter().map(|(from, to, _, _)| (*from, *to)).collect())
+            }
+            Self::GovSnapshot { strategy, .. } => strategy.validate(),
+            Self::Min(strategies) | Self::Max(strategies) | Self::Sum(strategies) => {
+                if strategies.is_empty() {
+                    return Err(ContractError::EmptyStrategies {});
+                }
+                strategies
+                    .iter()
+                    .try_for_each(|strategy| strategy.validate())
+            }
+            Self::Scheduled { phases } => {
+                if phases.is_empty() {
+                    return Err(ContractError::EmptyStrategies {});
+                }
+                for (index, window) in phases.windows(2).enumerate() {
+                    if window[1].0 <= window[0].0 {
+                        return Err(ContractError::UnsortedPhases {
+                            index: (index + 1) as u32,
+                        });
+                    }
+                }
+                phases
+                    .iter()
+                    .try_for_each(|(_, strategy)| strategy.validate())
+            }
+        }
+    }
+
+    fn validate_range(
+        min: Option<Uint128>,
+        max: Option<Uint128>,
+    ) -> Result<(), (Uint128, Uint128)> {
+        match (min, max) {
+            (Some(min), Some(max)) if max < min => Err((min, max)),
+            _ => Ok(()),
+        }
+    }
+
+    // 1. from
+    // 2. to
+    fn validate_stages(
+        stages: Vec<(Option<Uint128>, Option<Uint128>)>,
+    ) -> Result<(), ContractError> {
+        let mut prev_to = Some(Uint128::zero());
+        for (index, (from, to)) in stages.into_iter().enumerate() {
+            let index = index as u32;
+            let from = from.unwrap_or_else(Uint128::zero);
+            if let Some(to) = to {
+                if to <= from {
+                    return Err(ContractError::InvalidStageRange { index });
+                }
+            }
+
+            match prev_to {
+                Some(prev_to) if prev_to <= from => {}
+                _ => return Err(ContractError::OverlappedStages { index }),
+            }
+            prev_to = to;
+        }
+
+        Ok(())
+    }
+
+    fn available_cap_with_snapshot(
+        self,
+        deps: Deps,
+        time: u64,
+        address: String,
+        amount: Uint128,
+        snapshot: Option<Uint128>,
+    ) -> StdResult<CapStrategyResult> {
+        Ok(match self {
+            Self::Fixed {
+                min_user_cap,
+                max_user_cap,
+            } => Self::handle_fixed_strategy(amount, min_user_cap, max_user_cap),
             Self::GovFixed {
                 contract,
                 min_stake_amount,
                 min_user_cap,
                 max_user_cap,
             } => Self::handle_gov_fixed_strategy(
-                querier,
-                address,
+                Self::load_stake(deps.querier, contract, address, snapshot)?,
                 amount,
-                contract,
                 min_stake_amount,
                 min_user_cap,
                 max_user_cap,
@@ -83,27 +253,197 @@ impl CapStrategy {
                 min_stake_amount,
                 max_stake_amount,
             } => Self::handle_gov_linear_strategy(
-                querier,
-                address,
+                Self::load_stake(deps.querier, contract, address, snapshot)?,
                 amount,
-                contract,
                 cap_start,
                 cap_weight,
                 min_stake_amount,
                 max_stake_amount,
             ),
-            Self::GovStaged { contract, stages } => {
-                Self::handle_gov_staged_strategy(querier, address, amount, contract, stages)
+            Self::GovStaged { contract, stages } => Self::handle_gov_staged_strategy(
+                Self::load_stake(deps.querier, contract, address, snapshot)?,
+                amount,
+                stages,
+            ),
+            Self::GovLinearStaged { contract, stages } => Self::handle_gov_linear_staged_strategy(
+                Self::load_stake(deps.querier, contract, address, snapshot)?,
+                amount,
+                stages,
+            ),
+            Self::GovSnapshot {
+                registration_contract,
+                strategy,
+            } => {
+                // not registered = no stake
+                let mut stake = User::load_stake_snapshot(
+                    deps.storage,
+                    &deps.api.addr_canonicalize(address.as_str())?,
+                )?
+                .unwrap_or_default();
+                // stake withdrawn from gov after registration is not counted
+                if let Some(contract) = registration_contract {
+                    stake = min(
+                        stake,
+                        Self::load_stake(deps.querier, contract, address.clone(), None)?,
+                    );
+                }
+
+                return strategy.available_cap_with_snapshot(
+                    deps,
+                    time,
+                    address,
+                    amount,
+                    Some(stake),
+                );
             }
-            Self::GovLinearStaged { contract, stages } => {
-                Self::handle_gov_linear_staged_strategy(querier, address, amount, contract, stages)
+            Self::Min(strategies) => Self::handle_min_strategy(
+                strategies
+                    .into_iter()
+                    .map(|x| {
+                        x.available_cap_with_snapshot(deps, time, address.clone(), amount, snapshot)
+                    })
+                    .collect::<StdResult<_>>()?,
+            ),
+            Self::Max(strategies) => Self::handle_max_strategy(
+                strategies
+                    .into_iter()
+                    .map(|x| {
+                        x.available_cap_with_snapshot(deps, time, address.clone(), amount, snapshot)
+                    })
+                    .collect::<StdResult<_>>()?,
+            ),
+            Self::Scheduled { phases } => match phases
+                .into_iter()
+                .filter(|(start, _)| *start <= time)
+                .max_by_key(|(start, _)| *start)
+            {
+                Some((_, strategy)) => {
+                    return strategy
+                        .available_cap_with_snapshot(deps, time, address, amount, snapshot)
+                }
+                None => ERROR, // no active phase
+            },
+            Self::Sum(strategies) => {
+                // the largest minimum is applied once to the combined cap
+                let min_user_cap = strategies
+                    .iter()
+                    .map(|x| x.min_user_cap(time))
+                    .max()
+                    .unwrap_or_default();
+                if amount < min_user_cap {
+                    return Ok(ERROR);
+                }
+
+                Self::handle_sum_strategy(
+                    amount,
+                    // evaluate with zero amount to get the full cap of each strategy
+                    strategies
+                        .into_iter()
+                        .map(|x| {
+                            x.without_min_user_cap().available_cap_with_snapshot(
+                                deps,
+                                time,
+                                address.clone(),
+                                Uint128::zero(),
+                                snapshot,
+                            )
+                        })
+                        .collect::<StdResult<_>>()?,
+                )
+            }
+        })
+    }
+
+    fn min_user_cap(&self, time: u64) -> Uint128 {
+        match self {
+            Self::Fixed { min_user_cap, .. } | Self::GovFixed { min_user_cap, .. } => {
+                min_user_cap.unwrap_or_default()
+            }
+            Self::GovSnapshot { strategy, .. } => strategy.min_user_cap(time),
+            Self::Min(strategies) | Self::Max(strategies) | Self::Sum(strategies) => strategies
+                .iter()
+                .map(|strategy| strategy.min_user_cap(time))
+                .max()
+                .unwrap_or_default(),
+            Self::Scheduled { phases } => phases
+                .iter()
+                .filter(|(start, _)| *start <= time)
+                .max_by_key(|(start, _)| *start)
+                .map(|(_, strategy)| strategy.min_user_cap(time))
+                .unwrap_or_default(),
+            _ => Uint128::zero(),
+        }
+    }
+
+    fn without_min_user_cap(self) -> Self {
+        match self {
+            Self::Fixed { max_user_cap, .. } => Self::Fixed {
+                min_user_cap: None,
+                max_user_cap,
+            },
+            Self::GovFixed {
+                contract,
+                min_stake_amount,
+                max_user_cap,
+                ..
+            } => Self::GovFixed {
+                contract,
+                min_stake_amount,
+                min_user_cap: None,
+                max_user_cap,
+            },
+            Self::GovSnapshot {
+                registration_contract,
+                strategy,
+            } => Self::GovSnapshot {
+                registration_contract,
+                strategy: Box::new(strategy.without_min_user_cap()),
+            },
+            Self::Min(strategies) => Self::Min(
+                strategies
+                    .into_iter()
+                    .map(Self::without_min_user_cap)
+                    .collect(),
+            ),
+            Self::Max(strategies) => Self::Max(
+                strategies
+                    .into_iter()
+                    .map(Self::without_min_user_cap)
+                    .collect(),
+            ),
+            Self::Sum(strategies) => Self::Sum(
+                strategies
+                    .into_iter()
+                    .map(Self::without_min_user_cap)
+                    .collect(),
+            ),
+            Self::Scheduled { phases } => Self::Scheduled {
+                phases: phases
+                    .into_iter()
+                    .map(|(start, strategy)| (start, strategy.without_min_user_cap()))
+                    .collect(),
+            },
+            strategy => strategy,
+        }
+    }
+
+    fn load_stake(
+        querier: QuerierWrapper,
+        contract: String,
+        address: String,
+        snapshot: Option<Uint128>,
+    ) -> StdResult<Uint128> {
+        match snapshot {
+            Some(stake) => Ok(stake),
+            None => {
+                let staker: gov_resp::StakerResponse =
+                    querier.query_wasm_smart(contract, &gov_msg::QueryMsg::Staker { address })?;
+                Ok(staker.balance)
             }
         }
     }
 
     fn handle_fixed_strategy(
-        _querier: QuerierWrapper,
-        _address: String,
         amount: Uint128,
         min_user_cap: Option<Uint128>,
         max_user_cap: Option<Uint128>,
@@ -125,18 +465,13 @@ impl CapStrategy {
     }
 
     fn handle_gov_fixed_strategy(
-        querier: QuerierWrapper,
-        address: String,
+        stake: Uint128,
         amount: Uint128,
-        contract: String,
         min_stake_amount: Uint128,
         min_user_cap: Option<Uint128>,
         max_user_cap: Option<Uint128>,
     ) -> CapStrategyResult {
-        let staker: gov_resp::StakerResponse = querier
-            .query_wasm_smart(contract, &gov_msg::QueryMsg::Staker { address })
-            .unwrap();
-        if staker.balance < min_stake_amount {
+        if stake < min_stake_amount {
             return ERROR;
         }
 
@@ -156,29 +491,22 @@ impl CapStrategy {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     fn handle_gov_linear_strategy(
-        querier: QuerierWrapper,
-        address: String,
+        stake: Uint128,
         amount: Uint128,
-        contract: String,
         cap_start: Uint128,
         cap_weight: Decimal,
         min_stake_amount: Option<Uint128>,
         max_stake_amount: Option<Uint128>,
     ) -> CapStrategyResult {
-        let staker: gov_resp::StakerResponse = querier
-            .query_wasm_smart(contract, &gov_msg::QueryMsg::Staker { address })
-            .unwrap();
-
         let min_stake_amount = min_stake_amount.unwrap_or_else(Uint128::zero);
-        if staker.balance < min_stake_amount {
+        if stake < min_stake_amount {
             return ERROR;
         }
 
         match max_stake_amount {
             Some(max_stake_amount) => {
-                let dx = min(max_stake_amount, staker.balance) - min_stake_amount;
+                let dx = min(max_stake_amount, stake) - min_stake_amount;
                 let cap = cap_start + (dx * cap_weight);
 
                 if cap < amount {
@@ -192,25 +520,19 @@ impl CapStrategy {
     }
 
     fn handle_gov_staged_strategy(
-        querier: QuerierWrapper,
-        address: String,
+        stake: Uint128,
         amount: Uint128,
-        contract: String,
         stages: Vec<(Option<Uint128>, Option<Uint128>, Uint128)>,
     ) -> CapStrategyResult {
-        let staker: gov_resp::StakerResponse = querier
-            .query_wasm_smart(contract, &gov_msg::QueryMsg::Staker { address })
-            .unwrap();
-
         let mut cap = Uint128::zero();
         for (from, to, applied_cap) in stages.iter() {
             let from = from.unwrap_or(Uint128::zero());
             cap = max(
                 cap,
-                if from <= staker.balance {
+                if from <= stake {
                     match to {
                         Some(to) => {
-                            if staker.balance < *to {
+                            if stake < *to {
                                 *applied_cap
                             } else {
                                 Uint128::zero()
@@ -232,25 +554,19 @@ impl CapStrategy {
     }
 
     fn handle_gov_linear_staged_strategy(
-        querier: QuerierWrapper,
-        address: String,
+        stake: Uint128,
         amount: Uint128,
-        contract: String,
         stages: Vec<(Option<Uint128>, Option<Uint128>, Uint128, Decimal)>,
     ) -> CapStrategyResult {
-        let staker: gov_resp::StakerResponse = querier
-            .query_wasm_smart(contract, &gov_msg::QueryMsg::Staker { address })
-            .unwrap();
-
         let mut cap = Uint128::zero();
         for (from, to, cap_start, cap_weight) in stages.iter() {
             let from = from.unwrap_or(Uint128::zero());
             cap = max(
                 cap,
-                if from <= staker.balance {
+                if from <= stake {
                     match to {
                         Some(to) => {
-                            let dx = min(*to, staker.balance) - from;
+                            let dx = min(*to, stake) - from;
                             *cap_start + (*cap_weight * dx)
                         }
                         None => return UNLIMITED, // unlimited
@@ -267,6 +583,40 @@ impl CapStrategy {
             (cap - amount, false)
         }
     }
+
+    fn handle_min_strategy(results: Vec<CapStrategyResult>) -> CapStrategyResult {
+        results
+            .into_iter()
+            .filter(|(_, unlimited)| !unlimited)
+            .min_by_key(|(cap, _)| *cap)
+            .unwrap_or(UNLIMITED) // every strategy is unlimited
+    }
+
+    fn handle_max_strategy(results: Vec<CapStrategyResult>) -> CapStrategyResult {
+        if results.iter().any(|(_, unlimited)| *unlimited) {
+            return UNLIMITED;
+        }
+
+        results
+            .into_iter()
+            .max_by_key(|(cap, _)| *cap)
+            .unwrap_or(ERROR)
+    }
+
+    fn handle_sum_strategy(amount: Uint128, results: Vec<CapStrategyResult>) -> CapStrategyResult {
+        if results.iter().any(|(_, unlimited)| *unlimited) {
+            return UNLIMITED;
+        }
+
+        let cap = results
+            .into_iter()
+            .fold(Uint128::zero(), |acc, (cap, _)| acc + cap);
+        if cap < amount {
+            ERROR
+        } else {
+            (cap - amount, false)
+        }
+    }
 }
 
 impl From<swap_types::CapStrategy> for CapStrategy {
@@ -309,6 +659,28 @@ impl From<swap_types::CapStrategy> for CapStrategy {
             swap_types::CapStrategy::GovLinearStaged { contract, stages } => {
                 Self::GovLinearStaged { contract, stages }
             }
+            swap_types::CapStrategy::GovSnapshot {
+                registration_contract,
+                strategy,
+            } => Self::GovSnapshot {
+                registration_contract,
+                strategy: Box::new(Self::from(*strategy)),
+            },
+            swap_types::CapStrategy::Min(strategies) => {
+                Self::Min(strategies.into_iter().map(Self::from).collect())
+            }
+            swap_types::CapStrategy::Max(strategies) => {
+                Self::Max(strategies.into_iter().map(Self::from).collect())
+            }
+            swap_types::CapStrategy::Sum(strategies) => {
+                Self::Sum(strategies.into_iter().map(Self::from).collect())
+            }
+            swap_types::CapStrategy::Scheduled { phases } => Self::Scheduled {
+                phases: phases
+                    .into_iter()
+                    .map(|(start, strategy)| (start, Self::from(strategy)))
+                    .collect(),
+            },
         }
     }
 }
@@ -351,6 +723,28 @@ impl From<CapStrategy> for swap_types::CapStrategy {
             CapStrategy::GovLinearStaged { contract, stages } => {
                 Self::GovLinearStaged { contract, stages }
             }
+            CapStrategy::GovSnapshot {
+                registration_contract,
+                strategy,
+            } => Self::GovSnapshot {
+                registration_contract,
+                strategy: Box::new(Self::from(*strategy)),
+            },
+            CapStrategy::Min(strategies) => {
+                Self::Min(strategies.into_iter().map(Self::from).collect())
+            }
+            CapStrategy::Max(strategies) => {
+                Self::Max(strategies.into_iter().map(Self::from).collect())
+            }
+            CapStrategy::Sum(strategies) => {
+                Self::Sum(strategies.into_iter().map(Self::from).collect())
+            }
+            CapStrategy::Scheduled { phases } => Self::Scheduled {
+                phases: phases
+                    .into_iter()
+                    .map(|(start, strategy)| (start, Self::from(strategy)))
+                    .collect(),
+            },
         }
     }
 }
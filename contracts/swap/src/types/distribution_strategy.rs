@@ -1,7 +1,13 @@
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{Decimal, Fraction, Uint128};
 use pylon_gateway::swap_types;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::cmp::{max, min};
+
+use crate::error::ContractError;
+
+// 1 / ulp of Decimal
+const DECIMAL_FRACTIONAL: u128 = 1_000_000_000_000_000_000;
 
 // 1. release_amount
 // 2. fulfilled
@@ -19,6 +25,20 @@ pub enum DistributionStrategy {
         release_finish_time: u64,
         release_amount: Decimal,
     },
+    Periodic {
+        // first release time
+        start: u64,
+        interval: u64,
+        count: u64,
+        amount_per_period: Decimal,
+    },
+    CliffVesting {
+        // nothing released before cliff
+        cliff: u64,
+        start: u64,
+        finish: u64,
+        amount: Decimal,
+    },
 }
 
 impl DistributionStrategy {
@@ -28,11 +48,119 @@ impl DistributionStrategy {
             DistributionStrategy::Vesting {
                 release_start_time, ..
             } => time <= release_start_time,
+            DistributionStrategy::Periodic { start, .. } => time <= start,
+            DistributionStrategy::CliffVesting { cliff, start, .. } => time <= max(cliff, start),
         }
     }
 
-    pub fn release_amount_at(&self, time: &u64) -> DistributionStrategyResult {
+    pub fn validate(&self) -> Result<(), ContractError> {
         match self {
+            Self::Lockup { .. } => Ok(()),
+            Self::Vesting {
+                release_start_time,
+                release_finish_time,
+                ..
+            } => {
+                if release_finish_time < release_start_time {
+                    Err(ContractError::InvalidReleaseTime {
+                        start: *release_start_time,
+                        finish: *release_finish_time,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            Self::Periodic {
+                interval, count, ..
+            } => {
+                if *interval == 0 || *count == 0 {
+                    Err(ContractError::InvalidReleasePeriod {
+                        interval: *interval,
+                        count: *count,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            Self::CliffVesting {
+                cliff,
+                start,
+                finish,
+                ..
+            } => {
+                if finish < start {
+                    Err(ContractError::InvalidReleaseTime {
+                        start: *start,
+                        finish: *finish,
+                    })
+                } else if finish < cliff {
+                    Err(ContractError::InvalidReleaseTime {
+                        start: *cliff,
+                        finish: *finish,
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    // release amounts should be fully distributed
+    pub fn validate_all(strategies: &[Self]) -> Result<(), ContractError> {
+        strategies
+            .iter()
+            .try_for_each(|strategy| strategy.validate())?;
+        if strategies.is_empty() {
+            return Ok(());
+        }
+
+        let sum = strategies
+            .iter()
+            .try_fold(Decimal::zero(), |acc, strategy| {
+                Ok::<_, ContractError>(acc + strategy.release_amount()?)
+            })?;
+        // each amount may be rounded down by one ulp. e.g. thirds of the release
+        let tolerance = Decimal::from_ratio(strategies.len() as u128, DECIMAL_FRACTIONAL);
+        if Decimal::one() < sum || tolerance < Decimal::one() - sum {
+            return Err(ContractError::InvalidReleaseAmountSum { sum });
+        }
+
+        Ok(())
+    }
+
+    fn release_amount(&self) -> Result<Decimal, ContractError> {
+        Ok(match self {
+            Self::Lockup { release_amount, .. } => *release_amount,
+            Self::Vesting { release_amount, .. } => *release_amount,
+            Self::Periodic {
+                count,
+                amount_per_period,
+                ..
+            } => Self::multiply_periods(*amount_per_period, *count)?,
+            Self::CliffVesting { amount, .. } => *amount,
+        })
+    }
+
+    fn multiply_periods(amount_per_period: Decimal, count: u64) -> Result<Decimal, ContractError> {
+        let numerator = amount_per_period
+            .numerator()
+            .checked_mul(u128::from(count))
+            .ok_or(ContractError::ReleaseAmountOverflow {
+                amount_per_period,
+                count,
+            })?;
+
+        Ok(Decimal::from_ratio(
+            numerator,
+            amount_per_period.denominator(),
+        ))
+    }
+
+    pub fn release_amount_at(
+        &self,
+        time: &u64,
+    ) -> Result<DistributionStrategyResult, ContractError> {
+        Ok(match self {
             Self::Lockup {
                 release_time,
                 release_amount,
@@ -47,7 +175,21 @@ impl DistributionStrategy {
                 *release_finish_time,
                 *release_amount,
             ),
-        }
+            Self::Periodic {
+                start,
+                interval,
+                count,
+                amount_per_period,
+            } => {
+                Self::handle_periodic_strategy(time, *start, *interval, *count, *amount_per_period)?
+            }
+            Self::CliffVesting {
+                cliff,
+                start,
+                finish,
+                amount,
+            } => Self::handle_cliff_vesting_strategy(time, *cliff, *start, *finish, *amount),
+        })
     }
 
     fn handle_lockup_strategy(
@@ -82,6 +224,38 @@ impl DistributionStrategy {
             )
         }
     }
+
+    fn handle_periodic_strategy(
+        time: &u64,
+        start: u64,
+        interval: u64,
+        count: u64,
+        amount_per_period: Decimal,
+    ) -> Result<DistributionStrategyResult, ContractError> {
+        if time < &start {
+            return Ok((Decimal::zero(), false));
+        }
+
+        let released = min(count, (*time - start) / interval + 1);
+        Ok((
+            Self::multiply_periods(amount_per_period, released)?,
+            released == count,
+        ))
+    }
+
+    fn handle_cliff_vesting_strategy(
+        time: &u64,
+        cliff: u64,
+        start: u64,
+        finish: u64,
+        amount: Decimal,
+    ) -> DistributionStrategyResult {
+        if time < &cliff {
+            (Decimal::zero(), false)
+        } else {
+            Self::handle_vesting_strategy(time, start, finish, amount)
+        }
+    }
 }
 
 impl From<swap_types::DistributionStrategy> for DistributionStrategy {
@@ -103,6 +277,28 @@ impl From<swap_types::DistributionStrategy> for DistributionStrategy {
                 release_finish_time,
                 release_amount,
             },
+            swap_types::DistributionStrategy::Periodic {
+                start,
+                interval,
+                count,
+                amount_per_period,
+            } => Self::Periodic {
+                start,
+                interval,
+                count,
+                amount_per_period,
+            },
+            swap_types::DistributionStrategy::CliffVesting {
+                cliff,
+                start,
+                finish,
+                amount,
+            } => Self::CliffVesting {
+                cliff,
+                start,
+                finish,
+                amount,
+            },
         }
     }
 }
@@ -126,6 +322,28 @@ impl From<DistributionStrategy> for swap_types::DistributionStrategy {
                 release_finish_time,
                 release_amount,
             },
+            DistributionStrategy::Periodic {
+                start,
+                interval,
+                count,
+                amount_per_period,
+            } => Self::Periodic {
+                start,
+                interval,
+                count,
+                amount_per_period,
+            },
+            DistributionStrategy::CliffVesting {
+                cliff,
+                start,
+                finish,
+                amount,
+            } => Self::CliffVesting {
+                cliff,
+                start,
+                finish,
+                amount,
+            },
         }
     }
 }
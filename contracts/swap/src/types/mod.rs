@@ -1,2 +1,3 @@
 pub mod cap_strategy;
 pub mod distribution_strategy;
+pub mod referral_reward;
@@ -0,0 +1,53 @@
+use cosmwasm_std::{Decimal, Uint128};
+use pylon_gateway::swap_types;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferralReward {
+    Input { rate: Decimal },
+    Output { rate: Decimal },
+}
+
+impl ReferralReward {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        let rate = match self {
+            ReferralReward::Input { rate } => rate,
+            ReferralReward::Output { rate } => rate,
+        };
+        if Decimal::one() < *rate {
+            return Err(ContractError::InvalidReferralRewardRate { rate: *rate });
+        }
+
+        Ok(())
+    }
+
+    // (reward in input token, reward in output token)
+    pub fn reward_of(&self, swapped_in: Uint128, swapped_out: Uint128) -> (Uint128, Uint128) {
+        match self {
+            ReferralReward::Input { rate } => (swapped_in * *rate, Uint128::zero()),
+            ReferralReward::Output { rate } => (Uint128::zero(), swapped_out * *rate),
+        }
+    }
+}
+
+impl From<swap_types::ReferralReward> for ReferralReward {
+    fn from(reward: swap_types::ReferralReward) -> Self {
+        match reward {
+            swap_types::ReferralReward::Input { rate } => Self::Input { rate },
+            swap_types::ReferralReward::Output { rate } => Self::Output { rate },
+        }
+    }
+}
+
+impl From<ReferralReward> for swap_types::ReferralReward {
+    fn from(reward: ReferralReward) -> Self {
+        match reward {
+            ReferralReward::Input { rate } => Self::Input { rate },
+            ReferralReward::Output { rate } => Self::Output { rate },
+        }
+    }
+}
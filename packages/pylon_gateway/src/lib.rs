@@ -1,11 +1,15 @@
 pub mod factory_msg;
 pub mod factory_resp;
+pub mod pause;
 pub mod pool_msg;
 pub mod pool_resp;
 pub mod pool_resp_v2;
 pub mod pool_token_msg;
+pub mod reply;
+pub mod role;
 pub mod swap_msg;
 pub mod swap_resp;
 pub mod swap_resp_v2;
+pub mod swap_token_msg;
 pub mod swap_types;
 pub mod time_range;
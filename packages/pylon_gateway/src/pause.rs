@@ -0,0 +1,238 @@
+use cosmwasm_std::{Addr, Order, Response, StdError, StdResult, Storage};
+use cosmwasm_storage::{Bucket, ReadonlyBucket, ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub static KEY_PAUSE: &[u8] = b"pause";
+pub static KEY_PAUSE_SEQ: &[u8] = b"pause-seq";
+pub static PREFIX_PAUSE_HISTORY: &[u8] = b"pause-history";
+
+#[derive(Error, Debug, PartialEq)]
+pub enum PauseError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("paused (action: {action:?})")]
+    Paused { action: String },
+}
+
+// flags of both pool and swap. each contract only exposes the ones it uses
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Pause {
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub claim: bool,
+    #[serde(default)]
+    pub earn: bool,
+    #[serde(default)]
+    pub emergency: bool,
+}
+
+impl Pause {
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        Ok(ReadonlySingleton::<Self>::new(storage, KEY_PAUSE)
+            .may_load()?
+            .unwrap_or_default())
+    }
+
+    pub fn save(storage: &mut dyn Storage, data: &Self) -> StdResult<()> {
+        Singleton::<Self>::new(storage, KEY_PAUSE).save(data)
+    }
+
+    // validator
+    pub fn check_deposit(&self) -> Result<(), PauseError> {
+        check(self.deposit, "deposit")
+    }
+
+    pub fn check_withdraw(&self) -> Result<(), PauseError> {
+        check(self.withdraw, "withdraw")
+    }
+
+    pub fn check_claim(&self) -> Result<(), PauseError> {
+        check(self.claim, "claim")
+    }
+
+    pub fn check_earn(&self) -> Result<(), PauseError> {
+        check(self.earn, "earn")
+    }
+}
+
+fn check(paused: bool, action: &str) -> Result<(), PauseError> {
+    if paused {
+        return Err(PauseError::Paused {
+            action: action.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseEvent {
+    pub action: String,
+    pub paused: bool,
+    pub sender: Addr,
+    pub time: u64,
+}
+
+impl PauseEvent {
+    // ordered by sequence, ascending
+    pub fn load_range(
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: usize,
+    ) -> StdResult<Vec<(u64, Self)>> {
+        let start = start_after.map(|x| (x + 1).to_be_bytes().to_vec());
+
+        ReadonlyBucket::<Self>::new(storage, PREFIX_PAUSE_HISTORY)
+            .range(start.as_deref(), None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (k, v) = item?;
+                let mut seq = [0u8; 8];
+                seq.copy_from_slice(&k);
+                Ok((u64::from_be_bytes(seq), v))
+            })
+            .collect()
+    }
+
+    pub fn append(storage: &mut dyn Storage, event: &Self) -> StdResult<u64> {
+        let seq = ReadonlySingleton::<u64>::new(storage, KEY_PAUSE_SEQ)
+            .may_load()?
+            .unwrap_or_default();
+        Bucket::<Self>::new(storage, PREFIX_PAUSE_HISTORY).save(&seq.to_be_bytes(), event)?;
+        Singleton::<u64>::new(storage, KEY_PAUSE_SEQ).save(&(seq + 1))?;
+
+        Ok(seq)
+    }
+}
+
+// untouched if None
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PauseUpdate {
+    pub deposit: Option<bool>,
+    pub withdraw: Option<bool>,
+    pub claim: Option<bool>,
+    pub earn: Option<bool>,
+    pub emergency: Option<bool>,
+}
+
+// applies the changes and logs them. unchanged flags are not logged
+pub fn apply(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    time: u64,
+    update: PauseUpdate,
+) -> StdResult<Vec<PauseEvent>> {
+    let mut pause = Pause::load(storage)?;
+    let mut events = vec![];
+    let mut flags = [
+        ("deposit", &mut pause.deposit, update.deposit),
+        ("withdraw", &mut pause.withdraw, update.withdraw),
+        ("claim", &mut pause.claim, update.claim),
+        ("earn", &mut pause.earn, update.earn),
+        ("emergency", &mut pause.emergency, update.emergency),
+    ];
+    for (action, flag, paused) in flags.iter_mut() {
+        match *paused {
+            Some(paused) if paused != **flag => {
+                **flag = paused;
+                events.push(PauseEvent {
+                    action: action.to_string(),
+                    paused,
+                    sender: sender.clone(),
+                    time,
+                });
+            }
+            _ => {}
+        }
+    }
+    Pause::save(storage, &pause)?;
+
+    for event in events.iter() {
+        PauseEvent::append(storage, event)?;
+    }
+
+    Ok(events)
+}
+
+pub fn update(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    time: u64,
+    update: PauseUpdate,
+) -> Result<Response, PauseError> {
+    let events = apply(storage, sender, time, update)?;
+
+    let mut resp = Response::new().add_attribute("action", "update_pause");
+    for event in events.iter() {
+        resp = resp.add_attribute(
+            event.action.clone(),
+            if event.paused { "paused" } else { "unpaused" },
+        );
+    }
+
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Addr;
+
+    use super::*;
+
+    const GUARDIAN: &str = "guardian";
+
+    #[test]
+    fn apply_logs_changes_only() {
+        let mut deps = mock_dependencies(&[]);
+        let sender = Addr::unchecked(GUARDIAN);
+        let update = PauseUpdate {
+            deposit: Some(true),
+            claim: Some(false),
+            ..PauseUpdate::default()
+        };
+
+        let events = apply(&mut deps.storage, &sender, 1, update.clone()).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(apply(&mut deps.storage, &sender, 2, update)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Pause::load(&deps.storage).unwrap().check_deposit(),
+            Err(PauseError::Paused {
+                action: "deposit".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn load_range_paginates() {
+        let mut deps = mock_dependencies(&[]);
+        let sender = Addr::unchecked(GUARDIAN);
+        for time in 0..3u64 {
+            apply(
+                &mut deps.storage,
+                &sender,
+                time,
+                PauseUpdate {
+                    withdraw: Some(time % 2 == 0),
+                    ..PauseUpdate::default()
+                },
+            )
+            .unwrap();
+        }
+
+        let first = PauseEvent::load_range(&deps.storage, None, 2).unwrap();
+        assert_eq!(
+            first.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        let rest = PauseEvent::load_range(&deps.storage, Some(1), 2).unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].0, 2);
+        assert_eq!(rest[0].1.time, 2);
+    }
+}
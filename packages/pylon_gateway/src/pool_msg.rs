@@ -3,12 +3,16 @@ use cw20::Cw20ReceiveMsg;
 use pylon_utils::common::OrderBy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 use crate::time_range::TimeRange;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub pool_token_code_id: Option<u64>,
+    pub pool_token_name: Option<String>,
+    pub pool_token_symbol: Option<String>,
+    pub pool_token_decimals: Option<u8>,
     // pool
     pub share_token: String,
     pub deposit_time: Vec<TimeRange>,
@@ -25,7 +29,6 @@ pub struct InstantiateMsg {
 #[serde(rename_all = "snake_case")]
 pub enum ConfigureMsg {
     Config {
-        owner: Option<String>,
         share_token: Option<String>,
         reward_token: Option<String>,
         claim_time: Option<Vec<TimeRange>>,
@@ -41,9 +44,59 @@ pub enum ConfigureMsg {
     },
     AddPoolToken {
         code_id: u64,
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: Option<u8>,
+        // deactivates the current token in favor of the new one
+        #[serde(default)]
+        replace: bool,
+    },
+    AttachPoolToken {
+        address: String,
+    },
+    // owner only. the proposed owner takes over after AcceptOwner
+    ProposeOwner {
+        owner: String,
+    },
+    CancelOwnerProposal {},
+    AssignRole {
+        role: Role,
+        // revokes the role if None
+        address: Option<String>,
+    },
+    // pauses (true) or resumes (false) the given actions. untouched if None
+    Pause {
+        deposit: Option<bool>,
+        withdraw: Option<bool>,
+        claim: Option<bool>,
+    },
+    // owner only. EmergencyWithdraw ignores withdraw time and pause while set
+    Emergency {
+        emergency: bool,
     },
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    // Config without share_token or reward_token
+    ConfigAdmin,
+    // AddReward, SubReward
+    RewardManager,
+    // Pause
+    Guardian,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::ConfigAdmin => write!(f, "config_admin"),
+            Role::RewardManager => write!(f, "reward_manager"),
+            Role::Guardian => write!(f, "guardian"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -58,14 +111,21 @@ pub enum ExecuteMsg {
     Claim {
         target: Option<String>,
     },
+    // returns the whole stake without reward accounting. pending rewards are forfeited
+    EmergencyWithdraw {},
     // internal
     TransferInternal {
         owner: String,
         recipient: String,
         amount: Uint128,
     },
+    BurnInternal {
+        owner: String,
+        amount: Uint128,
+    },
     DepositInternal {
         sender: String,
+        recipient: Option<String>,
         amount: Uint128,
     },
     WithdrawInternal {
@@ -77,12 +137,14 @@ pub enum ExecuteMsg {
     },
     // owner
     Configure(ConfigureMsg),
+    // pending owner
+    AcceptOwner {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Cw20HookMsg {
-    Deposit {},
+    Deposit { recipient: Option<String> },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -114,6 +176,26 @@ pub enum QueryMsg {
         limit: Option<u32>,
         order: Option<OrderBy>,
     },
+    // stakers with a non-zero balance in ascending order
+    Accounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    StakerAt {
+        address: String,
+        height: u64,
+    },
+    TotalDepositAt {
+        height: u64,
+    },
+    TokenHistory {},
+    Roles {},
+    Pause {},
+    // ordered by id, ascending
+    PauseHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 /// We currently take no arguments for migrations
@@ -1,4 +1,4 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -19,3 +19,64 @@ pub struct ConfigResponse {
     pub reward_claim_time: Vec<TimeRange>,
     pub reward_distribution_time: TimeRange,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccountsResponse {
+    pub accounts: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakerAtResponse {
+    pub address: String,
+    pub height: u64,
+    pub staked: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TotalDepositAtResponse {
+    pub height: u64,
+    pub total_deposit: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoolTokenResponse {
+    pub address: String,
+    pub registered_at: u64,
+    pub deactivated_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenHistoryResponse {
+    pub tokens: Vec<PoolTokenResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RolesResponse {
+    pub owner: String,
+    pub pending_owner: Option<String>,
+    pub config_admin: Option<String>,
+    pub reward_manager: Option<String>,
+    pub guardian: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseResponse {
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub claim: bool,
+    pub emergency: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseEventResponse {
+    pub id: u64,
+    pub action: String,
+    pub paused: bool,
+    pub sender: String,
+    pub time: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseHistoryResponse {
+    pub events: Vec<PauseEventResponse>,
+}
@@ -1,9 +1,78 @@
+use cw20::Cw20QueryMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
     pub pool: String,
+    // overrides of the names derived from the pool and its tokens
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+// cw20 queries followed by snapshot and config queries. kept flat as untagged enums can not be parsed on chain
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Balance {
+        address: String,
+    },
+    TokenInfo {},
+    Minter {},
+    Allowance {
+        owner: String,
+        spender: String,
+    },
+    AllAllowances {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllAccounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    MarketingInfo {},
+    DownloadLogo {},
+    BalanceAt {
+        address: String,
+        height: u64,
+    },
+    TokenInfoAt {
+        height: u64,
+    },
+    Config {},
+}
+
+impl From<Cw20QueryMsg> for QueryMsg {
+    fn from(msg: Cw20QueryMsg) -> Self {
+        match msg {
+            Cw20QueryMsg::Balance { address } => QueryMsg::Balance { address },
+            Cw20QueryMsg::TokenInfo {} => QueryMsg::TokenInfo {},
+            Cw20QueryMsg::Minter {} => QueryMsg::Minter {},
+            Cw20QueryMsg::Allowance { owner, spender } => QueryMsg::Allowance { owner, spender },
+            Cw20QueryMsg::AllAllowances {
+                owner,
+                start_after,
+                limit,
+            } => QueryMsg::AllAllowances {
+                owner,
+                start_after,
+                limit,
+            },
+            Cw20QueryMsg::AllAccounts { start_after, limit } => {
+                QueryMsg::AllAccounts { start_after, limit }
+            }
+            Cw20QueryMsg::MarketingInfo {} => QueryMsg::MarketingInfo {},
+            Cw20QueryMsg::DownloadLogo {} => QueryMsg::DownloadLogo {},
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub pool: String,
 }
 
 /// We currently take no arguments for migrations
@@ -0,0 +1,202 @@
+use cosmwasm_std::{Binary, Reply};
+use thiserror::Error;
+
+// protobuf wire types used by MsgInstantiateContractResponse
+const WIRE_TYPE_VARINT: u8 = 0;
+const WIRE_TYPE_LENGTH_DELIMITED: u8 = 2;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ParseReplyError {
+    #[error("Gateway/Reply: submessage failed. (reason: {0})")]
+    SubMsgFailure(String),
+
+    #[error("Gateway/Reply: no data in reply.")]
+    NoData {},
+
+    #[error("Gateway/Reply: malformed reply data. (reason: {0})")]
+    ParseFailure(String),
+}
+
+/// Decoded `MsgInstantiateContractResponse` of the wasm module
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MsgInstantiateContractResponse {
+    pub contract_address: String,
+    pub data: Option<Binary>,
+}
+
+impl MsgInstantiateContractResponse {
+    pub fn decode(data: &[u8]) -> Result<Self, ParseReplyError> {
+        let mut decoded = Self::default();
+        let mut data = data;
+
+        while !data.is_empty() {
+            let key = parse_varint(&mut data)?;
+            let (field, wire_type) = (key >> 3, (key & 0x07) as u8);
+            match (field, wire_type) {
+                (1, WIRE_TYPE_LENGTH_DELIMITED) => {
+                    decoded.contract_address =
+                        String::from_utf8(parse_bytes(&mut data)?).map_err(|_| {
+                            ParseReplyError::ParseFailure(
+                                "contract address is not valid utf-8".to_string(),
+                            )
+                        })?;
+                }
+                (2, WIRE_TYPE_LENGTH_DELIMITED) => {
+                    decoded.data = Some(Binary::from(parse_bytes(&mut data)?));
+                }
+                // skip unknown fields for forward compatibility
+                (_, WIRE_TYPE_VARINT) => {
+                    parse_varint(&mut data)?;
+                }
+                (_, WIRE_TYPE_LENGTH_DELIMITED) => {
+                    parse_bytes(&mut data)?;
+                }
+                (_, wire_type) => {
+                    return Err(ParseReplyError::ParseFailure(format!(
+                        "unsupported wire type {}",
+                        wire_type
+                    )))
+                }
+            }
+        }
+
+        if decoded.contract_address.is_empty() {
+            return Err(ParseReplyError::ParseFailure(
+                "missing contract address".to_string(),
+            ));
+        }
+
+        Ok(decoded)
+    }
+}
+
+/// Decodes the reply of a `WasmMsg::Instantiate` submessage
+pub fn parse_reply_instantiate_data(
+    msg: Reply,
+) -> Result<MsgInstantiateContractResponse, ParseReplyError> {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(ParseReplyError::SubMsgFailure)?
+        .data
+        .ok_or(ParseReplyError::NoData {})?;
+
+    MsgInstantiateContractResponse::decode(data.as_slice())
+}
+
+fn parse_varint(data: &mut &[u8]) -> Result<u64, ParseReplyError> {
+    let mut value = 0u64;
+    for (i, byte) in data.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (i * 7);
+        if byte & 0x80 == 0 {
+            *data = &data[i + 1..];
+            return Ok(value);
+        }
+    }
+
+    Err(ParseReplyError::ParseFailure("invalid varint".to_string()))
+}
+
+fn parse_bytes(data: &mut &[u8]) -> Result<Vec<u8>, ParseReplyError> {
+    let len = parse_varint(data)? as usize;
+    if data.len() < len {
+        return Err(ParseReplyError::ParseFailure(
+            "field length exceeds data".to_string(),
+        ));
+    }
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+
+    Ok(bytes.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_varint(encoded: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            encoded.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        encoded.push(value as u8);
+    }
+
+    fn encode_bytes(encoded: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+        encode_varint(encoded, field << 3 | u64::from(WIRE_TYPE_LENGTH_DELIMITED));
+        encode_varint(encoded, bytes.len() as u64);
+        encoded.extend_from_slice(bytes);
+    }
+
+    fn parse_failure(data: &[u8]) -> String {
+        match MsgInstantiateContractResponse::decode(data) {
+            Ok(_) => panic!("Must return error"),
+            Err(ParseReplyError::ParseFailure(reason)) => reason,
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let mut encoded = vec![];
+        encode_bytes(&mut encoded, 1, b"contract");
+        encode_bytes(&mut encoded, 2, b"data");
+
+        assert_eq!(
+            MsgInstantiateContractResponse::decode(&encoded).unwrap(),
+            MsgInstantiateContractResponse {
+                contract_address: "contract".to_string(),
+                data: Some(Binary::from(b"data".to_vec())),
+            }
+        );
+    }
+
+    #[test]
+    fn decode_skips_unknown_fields() {
+        let mut encoded = vec![];
+        encode_varint(&mut encoded, 3 << 3 | u64::from(WIRE_TYPE_VARINT));
+        encode_varint(&mut encoded, 300);
+        encode_bytes(&mut encoded, 4, b"unknown");
+        encode_bytes(&mut encoded, 1, b"contract");
+
+        assert_eq!(
+            MsgInstantiateContractResponse::decode(&encoded).unwrap(),
+            MsgInstantiateContractResponse {
+                contract_address: "contract".to_string(),
+                data: None,
+            }
+        );
+    }
+
+    #[test]
+    fn fail_truncated_varint() {
+        // continuation bit set on the last byte
+        assert_eq!(parse_failure(&[0x0a, 0x80]), "invalid varint");
+        // longer than 10 bytes
+        assert_eq!(parse_failure(&[0xff; 11]), "invalid varint");
+    }
+
+    #[test]
+    fn fail_bad_length() {
+        let mut encoded = vec![];
+        encode_varint(&mut encoded, 1 << 3 | u64::from(WIRE_TYPE_LENGTH_DELIMITED));
+        encode_varint(&mut encoded, 10);
+        encoded.extend_from_slice(b"short");
+
+        assert_eq!(parse_failure(&encoded), "field length exceeds data");
+    }
+
+    #[test]
+    fn fail_unsupported_wire_type() {
+        // field 1, fixed64
+        assert_eq!(parse_failure(&[0x09]), "unsupported wire type 1");
+    }
+
+    #[test]
+    fn fail_missing_contract_address() {
+        let mut encoded = vec![];
+        encode_bytes(&mut encoded, 2, b"data");
+
+        assert_eq!(parse_failure(&encoded), "missing contract address");
+    }
+}
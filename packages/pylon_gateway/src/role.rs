@@ -0,0 +1,212 @@
+use cosmwasm_std::{attr, Addr, Api, Response, StdError, StdResult, Storage};
+use cosmwasm_storage::{ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use thiserror::Error;
+
+pub static KEY_ROLES: &[u8] = b"roles";
+pub const NOT_ASSIGNED: &str = "<not assigned>";
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RoleError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("unauthorized (action: {action:?}, expected: {expected:?}, actual: {actual:?})")]
+    Unauthorized {
+        action: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+pub trait AdminRole: Serialize + DeserializeOwned + PartialEq + Clone + Display {}
+
+impl<T: Serialize + DeserializeOwned + PartialEq + Clone + Display> AdminRole for T {}
+
+// the owner itself stays in the config of each contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Roles<R> {
+    pub pending_owner: Option<Addr>,
+    pub holders: Vec<(R, Addr)>,
+}
+
+impl<R: AdminRole> Roles<R> {
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        Ok(ReadonlySingleton::<Self>::new(storage, KEY_ROLES)
+            .may_load()?
+            .unwrap_or(Roles {
+                pending_owner: None,
+                holders: vec![],
+            }))
+    }
+
+    pub fn save(storage: &mut dyn Storage, data: &Self) -> StdResult<()> {
+        Singleton::<Self>::new(storage, KEY_ROLES).save(data)
+    }
+
+    pub fn holder(&self, role: &R) -> Option<&Addr> {
+        self.holders
+            .iter()
+            .find(|(holder_role, _)| holder_role == role)
+            .map(|(_, address)| address)
+    }
+
+    // the owner holds every role, so None means owner only
+    pub fn is_granted(&self, role: Option<&R>, address: &Addr) -> bool {
+        matches!(role, Some(role) if self.holder(role) == Some(address))
+    }
+}
+
+pub fn propose_owner<R: AdminRole>(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    owner: String,
+) -> Result<Response, RoleError> {
+    let mut roles = Roles::<R>::load(storage)?;
+    let owner = api.addr_validate(owner.as_str())?;
+    roles.pending_owner = Some(owner.clone());
+    Roles::save(storage, &roles)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "propose_owner"),
+        attr("owner", owner.to_string()),
+    ]))
+}
+
+pub fn cancel_owner_proposal<R: AdminRole>(
+    storage: &mut dyn Storage,
+) -> Result<Response, RoleError> {
+    let mut roles = Roles::<R>::load(storage)?;
+    roles.pending_owner = None;
+    Roles::save(storage, &roles)?;
+
+    Ok(Response::new().add_attribute("action", "cancel_owner_proposal"))
+}
+
+// delegated roles are revoked, so the new owner starts without the previous owner's delegates.
+// storing the sender as the new owner is left to the caller
+pub fn accept_owner<R: AdminRole>(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    previous: &Addr,
+) -> Result<Response, RoleError> {
+    let roles = Roles::<R>::load(storage)?;
+    match roles.pending_owner.as_ref() {
+        Some(pending_owner) if pending_owner == sender => {}
+        pending_owner => {
+            return Err(RoleError::Unauthorized {
+                action: "accept_owner".to_string(),
+                expected: pending_owner
+                    .map(|x| x.to_string())
+                    .unwrap_or_else(|| NOT_ASSIGNED.to_string()),
+                actual: sender.to_string(),
+            })
+        }
+    }
+
+    Roles::<R>::save(
+        storage,
+        &Roles {
+            pending_owner: None,
+            holders: vec![],
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "accept_owner"),
+        attr("owner", sender.to_string()),
+        attr("previous", previous.to_string()),
+    ]))
+}
+
+pub fn assign_role<R: AdminRole>(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    role: R,
+    address: Option<String>,
+) -> Result<Response, RoleError> {
+    let mut roles = Roles::<R>::load(storage)?;
+    let address = address.map(|x| api.addr_validate(x.as_str())).transpose()?;
+    roles
+        .holders
+        .retain(|(holder_role, _)| *holder_role != role);
+    if let Some(address) = address.as_ref() {
+        roles.holders.push((role.clone(), address.clone()));
+    }
+    Roles::save(storage, &roles)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "assign_role"),
+        attr("role", role.to_string()),
+        attr(
+            "address",
+            address
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| NOT_ASSIGNED.to_string()),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, MockApi};
+    use cosmwasm_std::Addr;
+
+    use super::*;
+    use crate::pool_msg::Role;
+
+    const OWNER: &str = "owner";
+    const CANDIDATE: &str = "candidate";
+
+    #[test]
+    fn accept_owner_revokes_roles() {
+        let mut deps = mock_dependencies(&[]);
+        let api = MockApi::default();
+        assign_role(
+            &mut deps.storage,
+            &api,
+            Role::ConfigAdmin,
+            Some(CANDIDATE.to_string()),
+        )
+        .unwrap();
+        propose_owner::<Role>(&mut deps.storage, &api, CANDIDATE.to_string()).unwrap();
+
+        accept_owner::<Role>(
+            &mut deps.storage,
+            &Addr::unchecked(CANDIDATE),
+            &Addr::unchecked(OWNER),
+        )
+        .unwrap();
+        assert_eq!(
+            Roles::<Role>::load(&deps.storage).unwrap(),
+            Roles {
+                pending_owner: None,
+                holders: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn assign_role_replaces_holder() {
+        let mut deps = mock_dependencies(&[]);
+        let api = MockApi::default();
+        for address in [OWNER, CANDIDATE].iter() {
+            assign_role(
+                &mut deps.storage,
+                &api,
+                Role::RewardManager,
+                Some(address.to_string()),
+            )
+            .unwrap();
+        }
+
+        let roles = Roles::<Role>::load(&deps.storage).unwrap();
+        assert_eq!(roles.holders.len(), 1);
+        assert!(roles.is_granted(Some(&Role::RewardManager), &Addr::unchecked(CANDIDATE)));
+        assert!(!roles.is_granted(Some(&Role::ConfigAdmin), &Addr::unchecked(CANDIDATE)));
+        assert!(!roles.is_granted(None, &Addr::unchecked(CANDIDATE)));
+    }
+}
@@ -1,9 +1,11 @@
 use cosmwasm_std::{Decimal, Uint128};
+use cw20::Cw20ReceiveMsg;
 use pylon_utils::common::OrderBy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
-use crate::swap_types::{CapStrategy, DistributionStrategy};
+use crate::swap_types::{CapStrategy, DistributionStrategy, LiquiditySeed, ReferralReward};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -19,19 +21,29 @@ pub struct InstantiateMsg {
     pub deposit_cap_strategy: Option<CapStrategy>,
     pub distribution_strategies: Vec<DistributionStrategy>,
     pub whitelist_enabled: bool,
+    // refund mode if total swapped amount is below this at finish
+    pub soft_cap: Option<Uint128>,
+    // unlocks earnings as buyers claim output tokens
+    #[serde(default)]
+    pub earn_by_claim: bool,
+    // manages protocol fee
+    #[serde(default)]
+    pub protocol_admin: Option<String>,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ConfigureMsg {
     Config {
-        owner: Option<String>,
         beneficiary: Option<String>,
         input_token: Option<String>,
         output_token: Option<String>,
         deposit_cap_strategy: Option<CapStrategy>,
         distribution_strategies: Option<Vec<DistributionStrategy>>,
         whitelist_enabled: Option<bool>,
+        receipt_token: Option<String>,
+        earn_by_claim: Option<bool>,
     },
     State {
         x_liquidity: Option<Uint128>,
@@ -41,16 +53,145 @@ pub enum ConfigureMsg {
         whitelist: bool,
         candidates: Vec<String>,
     },
+    Snapshot {
+        stakes: Vec<(String, Uint128)>,
+    },
+    TierStrategy {
+        tier: String,
+        // removes the tier if None
+        distribution_strategies: Option<Vec<DistributionStrategy>>,
+    },
+    Tier {
+        // assigns the global strategies if None
+        tier: Option<String>,
+        candidates: Vec<String>,
+    },
+    DepositTier {
+        // tier assigned to users without tier on deposit
+        tier: Option<String>,
+    },
+    Beneficiaries {
+        // (address, share) pairs. empty list resets to the beneficiary
+        proceeds: Option<Vec<(String, Decimal)>>,
+        penalty: Option<Vec<(String, Decimal)>>,
+    },
+    LiquiditySeed {
+        // disables liquidity seeding if None
+        liquidity_seed: Option<LiquiditySeed>,
+    },
+    DepositRoute {
+        // native denom or cw20 address
+        asset: String,
+        // removes the route if None
+        pair: Option<String>,
+    },
+    ReferralReward {
+        // disables referral reward if None
+        reward: Option<ReferralReward>,
+    },
+    // owner only. the proposed owner takes over after AcceptOwner
+    ProposeOwner {
+        owner: String,
+    },
+    CancelOwnerProposal {},
+    AssignRole {
+        role: Role,
+        // revokes the role if None
+        address: Option<String>,
+    },
+    // pauses (true) or resumes (false) the given actions. untouched if None
+    Pause {
+        deposit: Option<bool>,
+        withdraw: Option<bool>,
+        claim: Option<bool>,
+        earn: Option<bool>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    // Config without beneficiary or tokens, Snapshot, TierStrategy, DepositTier
+    ConfigAdmin,
+    // Whitelist, Tier
+    WhitelistManager,
+    // ReferralReward
+    RewardManager,
+    // Pause
+    Guardian,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::ConfigAdmin => write!(f, "config_admin"),
+            Role::WhitelistManager => write!(f, "whitelist_manager"),
+            Role::RewardManager => write!(f, "reward_manager"),
+            Role::Guardian => write!(f, "guardian"),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     Configure(Box<ConfigureMsg>),
-    Deposit {},
-    Withdraw { amount: Uint128 },
+    Register {},
+    Deposit {
+        referrer: Option<String>,
+        // credits the deposit to another address
+        recipient: Option<String>,
+    },
+    // swaps other native denom into input token and deposits
+    DepositRouted {
+        min_receive: Uint128,
+        referrer: Option<String>,
+    },
+    Receive(Cw20ReceiveMsg),
+    Withdraw {
+        amount: Uint128,
+    },
     Claim {},
-    Earn {},
+    ClaimReferralReward {},
+    // owner. seeds terraswap pair after the sale
+    ProvideLiquidity {},
+    // owner. sends lp tokens locked by ProvideLiquidity
+    ReleaseLiquidity {
+        recipient: String,
+        // releases all locked lp tokens if None
+        amount: Option<Uint128>,
+    },
+    Earn {
+        // earns all earnable amount if None
+        amount: Option<Uint128>,
+    },
+    Refund {},
+    // owner
+    Cancel {},
+    // pending owner
+    AcceptOwner {},
+    // protocol admin
+    ConfigureProtocol {
+        admin: Option<String>,
+        fee_rate: Option<Decimal>,
+        fee_collector: Option<String>,
+        fee_on_claim: Option<bool>,
+    },
+    // receipt token only
+    TransferInternal {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20HookMsg {
+    DepositRouted {
+        min_receive: Uint128,
+        referrer: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -83,6 +224,29 @@ pub enum QueryMsg {
         limit: Option<u32>,
         order: Option<OrderBy>,
     },
+    StakeSnapshotOf {
+        address: String,
+    },
+    CapPhase {},
+    Referrer {
+        address: String,
+    },
+    TopReferrers {
+        limit: Option<u32>,
+    },
+    Beneficiaries {},
+    Roles {},
+    Pause {},
+    // ordered by id, ascending
+    PauseHistory {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    ValidateConfig {
+        // validates the stored strategy if None
+        deposit_cap_strategy: Option<CapStrategy>,
+        distribution_strategies: Option<Vec<DistributionStrategy>>,
+    },
 
     // common
     CurrentPrice {},
@@ -3,7 +3,7 @@ use cw20::Denom;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::swap_types::{CapStrategy, DistributionStrategy};
+use crate::swap_types::{CapStrategy, DistributionStrategy, LiquiditySeed, ReferralReward};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
@@ -20,18 +20,43 @@ pub struct ConfigResponse {
     // strategies
     pub deposit_cap_strategy: Option<CapStrategy>,
     pub distribution_strategies: Vec<DistributionStrategy>,
+    pub tier_distribution_strategies: Vec<(String, Vec<DistributionStrategy>)>,
+    pub deposit_tier: Option<String>,
     pub whitelist_enabled: bool,
+    pub receipt_token: Option<String>,
+    pub soft_cap: Option<Uint128>,
+    pub earn_by_claim: bool,
+    pub protocol_admin: Option<String>,
+    pub protocol_fee_rate: Decimal,
+    pub protocol_fee_collector: Option<String>,
+    pub protocol_fee_on_claim: bool,
+    pub liquidity_seed: Option<LiquiditySeed>,
+    pub deposit_routes: Vec<(String, String)>,
+    pub referral_reward: Option<ReferralReward>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct StateResponse {
     pub total_swapped: Uint128, // total supply
     pub total_claimed: Uint128,
+    pub cancelled: bool,
+    pub refund_mode: bool,
+    pub total_raised: Uint128,
+    pub total_refunded: Uint128,
+    pub total_earned: Uint128,
+    pub total_protocol_fee_input: Uint128,
+    pub total_protocol_fee_output: Uint128,
+    pub liquidity_seeded: bool,
+    pub liquidity_seed_input: Uint128,
+    pub total_referral_reward_in: Uint128,
+    pub total_referral_reward_out: Uint128,
+    pub earnable: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct UserResponse {
     pub whitelisted: bool,
+    pub tier: Option<String>,
     pub swapped_in: Uint128,
     pub available_cap: Option<Uint128>, // None = unlimited
     pub reward_total: Uint128,
@@ -42,3 +67,75 @@ pub struct UserResponse {
 pub struct UsersResponse {
     pub users: Vec<(String, UserResponse)>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakeSnapshotOfResponse {
+    pub amount: Option<Uint128>, // None = not recorded
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CapPhaseResponse {
+    pub phase: Option<u32>, // None = not scheduled or not started
+    pub started_at: Option<u64>,
+    pub next_phase_at: Option<u64>, // None = last phase
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValidateConfigResponse {
+    pub valid: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BeneficiariesResponse {
+    pub proceeds: Vec<(String, Decimal)>,
+    pub penalty: Vec<(String, Decimal)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RolesResponse {
+    pub owner: String,
+    pub pending_owner: Option<String>,
+    pub config_admin: Option<String>,
+    pub whitelist_manager: Option<String>,
+    pub reward_manager: Option<String>,
+    pub guardian: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseResponse {
+    pub deposit: bool,
+    pub withdraw: bool,
+    pub claim: bool,
+    pub earn: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseEventResponse {
+    pub id: u64,
+    pub action: String,
+    pub paused: bool,
+    pub sender: String,
+    pub time: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PauseHistoryResponse {
+    pub events: Vec<PauseEventResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferrerResponse {
+    pub address: String,
+    pub referred_in: Uint128,
+    pub referred_count: u64,
+    pub reward_in: Uint128,
+    pub reward_in_claimed: Uint128,
+    pub reward_out: Uint128,
+    pub reward_out_claimed: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TopReferrersResponse {
+    pub referrers: Vec<ReferrerResponse>,
+}
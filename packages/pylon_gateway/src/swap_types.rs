@@ -37,6 +37,24 @@ pub enum CapStrategy {
         // 4. cap_weight
         stages: Vec<(Option<Uint128>, Option<Uint128>, Uint128, Decimal)>,
     },
+    GovSnapshot {
+        // gov contract queried on registration.
+        // only owner-submitted snapshots are used if None
+        registration_contract: Option<String>,
+        // evaluated with the recorded stake instead of the current gov balance
+        strategy: Box<CapStrategy>,
+    },
+    // smallest available cap of all strategies
+    Min(Vec<CapStrategy>),
+    // largest available cap of all strategies
+    Max(Vec<CapStrategy>),
+    // sum of caps of all strategies
+    Sum(Vec<CapStrategy>),
+    Scheduled {
+        // 1. phase start time
+        // 2. strategy applied from the start time
+        phases: Vec<(u64, CapStrategy)>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -51,4 +69,37 @@ pub enum DistributionStrategy {
         release_finish_time: u64,
         release_amount: Decimal,
     },
+    Periodic {
+        // first release time
+        start: u64,
+        interval: u64,
+        count: u64,
+        amount_per_period: Decimal,
+    },
+    CliffVesting {
+        // nothing released before cliff
+        cliff: u64,
+        start: u64,
+        finish: u64,
+        amount: Decimal,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LiquiditySeed {
+    pub pair: String,
+    // fraction of raised input tokens
+    pub input_ratio: Decimal,
+    pub output_amount: Uint128,
+    // locks lp tokens in the swap contract if None
+    pub lp_recipient: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferralReward {
+    // paid from raised input token. rate of referee's swapped_in
+    Input { rate: Decimal },
+    // paid from output token allocation. rate of referee's swapped_out
+    Output { rate: Decimal },
 }